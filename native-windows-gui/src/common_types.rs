@@ -18,6 +18,18 @@ pub enum VTextAlign {
     Bottom
 }
 
+/**
+    The origin of a control position. See `ControlHandle::position_in`
+
+    * Screen: The upper left corner of the primary monitor
+    * Parent: The upper left corner of the client area of the parent. Top level windows (including popups) have no parent and use the screen.
+*/
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Coords {
+    Screen,
+    Parent
+}
+
 pub mod keys {
     //! Windows virtual key code
     
@@ -42,7 +42,7 @@ Button is not behind any features.
 
 **Builder parameters:**
   * `parent`:   **Required.** The button parent container.
-  * `text`:     The button text.
+  * `text`:     The button text. A `&` marks a mnemonic, use `&&` (or `escape_ampersand`) to display a literal ampersand.
   * `size`:     The button size.
   * `position`: The button position.
   * `enabled`:  If the button can be used by the user. It also has a grayed out look if disabled.
@@ -218,6 +218,23 @@ impl Button {
         unsafe { wh::set_window_size(handle, x, y, false) }
     }
 
+    /// Returns the size the button needs to display its text and its image (bitmap or icon) with the current font.
+    /// The button itself is not resized. See `fit_to_content`.
+    pub fn ideal_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_ideal_button_size(handle) }
+    }
+
+    /// Resize the button so that its text and its image fit inside. Useful when the text of the button changes at runtime (ex: translations).
+    /// The position of the button is kept. Note that a layout managing the button may resize it again.
+    pub fn fit_to_content(&self) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            let (w, h) = wh::get_ideal_button_size(handle);
+            wh::set_window_size(handle, w, h, false);
+        }
+    }
+
     /// Returns the position of the button in the parent window
     pub fn position(&self) -> (i32, i32) {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
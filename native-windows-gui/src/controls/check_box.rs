@@ -16,7 +16,7 @@ bitflags! {
         * VISIBLE:  The checkbox is immediatly visible after creation
         * DISABLED: The checkbox cannot be interacted with by the user. It also has a grayed out look.
         * TRISTATE: The checkbox will have a 3rd state
-        * PUSHLIKE: The checkbox will look like a regular button
+        * PUSHLIKE: The checkbox will look like a regular button that stays pressed while it is checked (ex: a bold toggle in a toolbar)
         * TAB_STOP: The control can be selected using tab navigation
     */
     pub struct CheckBoxFlags: u32 {
@@ -47,7 +47,7 @@ CheckBox is not behind any features.
 
 **Builder parameters:**
   * `parent`:           **Required.** The checkbox parent container.
-  * `text`:             The checkbox text.
+  * `text`:             The checkbox text. A `&` marks a mnemonic, use `&&` (or `escape_ampersand`) to display a literal ampersand.
   * `size`:             The checkbox size.
   * `position`:         The checkbox position.
   * `enabled`:          If the checkbox can be used by the user. It also has a grayed out look if disabled.
@@ -59,7 +59,7 @@ CheckBox is not behind any features.
   * `focus`:            The control receive focus after being created
 
 **Control events:**
-  * `OnButtonClick`: When the checkbox is clicked once by the user
+  * `OnButtonClick`: When the checkbox is clicked once by the user. `EventData::on_button_click` returns the new check state
   * `OnButtonDoubleClick`: When the checkbox is clicked twice rapidly by the user
   * `MousePress(_)`: Generic mouse press events on the checkbox
   * `OnMouseMove`: Generic mouse mouse event
@@ -121,7 +121,33 @@ impl CheckBox {
             false => BS_AUTOCHECKBOX
         };
 
-        wh::send_message(handle, BM_SETSTYLE, style as WPARAM, 1);
+        let push_like = wh::get_style(handle) & BS_PUSHLIKE;
+        wh::send_message(handle, BM_SETSTYLE, (style | push_like) as WPARAM, 1);
+    }
+
+    /// Return `true` if the checkbox looks like a button that stays pressed while it is checked
+    pub fn push_like(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & BS_PUSHLIKE == BS_PUSHLIKE
+    }
+
+    /// Display the checkbox as a button that stays pressed while it is checked, or as a regular checkbox.
+    /// The check state and the events are the same in both cases.
+    pub fn set_push_like(&self, push_like: bool) {
+        use winapi::um::winuser::InvalidateRect;
+        use std::ptr;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        // `BM_SETSTYLE` only replaces the button type, so the push-like bit is set on the window style directly
+        let style = wh::get_style(handle);
+        let style = match push_like {
+            true => style | BS_PUSHLIKE,
+            false => style & !BS_PUSHLIKE
+        };
+
+        wh::set_style(handle, style);
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
     }
 
     /// Return the check state of the check box
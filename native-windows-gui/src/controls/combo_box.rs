@@ -1,10 +1,10 @@
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::{LPARAM, WPARAM};
-use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
-use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
+use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP, WS_HSCROLL, CBS_OWNERDRAWFIXED, CBS_HASSTRINGS, CBS_SIMPLE, CBS_DROPDOWN, CBS_DROPDOWNLIST, CBS_AUTOHSCROLL};
+use crate::win32::base_helper::check_hwnd;
 use crate::win32::window_helper as wh;
-use crate::{Font, NwgError};
-use super::{ControlHandle, ControlBase};
+use crate::{Font, NwgError, RawEventHandler};
+use super::{ControlHandle, ControlBase, WideBuffer};
 use std::cell::{Ref, RefMut, RefCell};
 use std::fmt::Display;
 use std::mem;
@@ -21,12 +21,22 @@ bitflags! {
         * VISIBLE:  The combobox is immediatly visible after creation
         * DISABLED: The combobox cannot be interacted with by the user. It also has a grayed out look.
         * TAB_STOP: The control can be selected using tab navigation
+        * OWNER_DRAW: The items are painted by the application in the `OnDrawItem` event
+        * EDITABLE: The user can type a value that is not in the list. Read it with `ComboBox::text`
+        * SIMPLE: Like `EDITABLE`, but the list is always displayed under the selection field. The control size must include the list height. Cannot be combined with `EDITABLE`.
+        * H_SCROLL: The list displays a horizontal scrollbar when its items are wider than the list. See `ComboBox::fit_dropdown_width`
+
+        Without `EDITABLE` or `SIMPLE`, the combobox is a dropdown list and the user can only pick one of the items.
     */
     pub struct ComboBoxFlags: u32 {
         const NONE = 0;
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const TAB_STOP = WS_TABSTOP;
+        const OWNER_DRAW = CBS_OWNERDRAWFIXED | CBS_HASSTRINGS;
+        const EDITABLE = CBS_DROPDOWN | CBS_AUTOHSCROLL;
+        const SIMPLE = CBS_SIMPLE | CBS_AUTOHSCROLL;
+        const H_SCROLL = WS_HSCROLL;
     }
 }
 
@@ -47,11 +57,15 @@ Requires the `combobox` feature.
   * `collection`:     The default collection of the combobox
   * `selected_index`: The default selected index. None means no values are selected.  
   * `focus`:          The control receive focus after being created
+  * `item_height`:    The height of the items in the dropdown list. Mostly useful with the `OWNER_DRAW` flag. None uses the system default.
 
 **Control events:**
   * `OnComboBoxClosed`: When the combobox dropdown is closed
   * `OnComboBoxDropdown`: When the combobox dropdown is opened
   * `OnComboxBoxSelection`: When a new value in a combobox is choosen
+  * `OnComboBoxTextChanged`: When the user types in a combobox with the `EDITABLE` or `SIMPLE` flag
+  * `OnComboBoxSubmit`: When the user presses ENTER in a combobox with the `EDITABLE` or `SIMPLE` flag. The dropdown list must be closed.
+  * `OnDrawItem`: When an item must be painted. Only sent if the combobox has the `OWNER_DRAW` flag
   * `MousePress(_)`: Generic mouse press events on the checkbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -74,6 +88,7 @@ fn build_combobox(combo: &mut nwg::ComboBox<&'static str>, window: &nwg::Window)
 #[derive(Default)]
 pub struct ComboBox<D: Display+Default> {
     pub handle: ControlHandle,
+    handler0: RefCell<Option<RawEventHandler>>,
     collection: RefCell<Vec<D>>
 }
 
@@ -90,6 +105,7 @@ impl<D: Display+Default> ComboBox<D> {
             font: None,
             collection: None,
             selected_index: None,
+            item_height: None,
             parent: None
         }
     }
@@ -125,12 +141,21 @@ impl<D: Display+Default> ComboBox<D> {
 
         for item in col.iter() {
             let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+            let mut display_os = WideBuffer::from_str(&display);
+            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_lparam());
         }
     }
 
-    /// Show or hide the dropdown of the combox
+    /// Return `true` if the dropdown of the combobox is visible
+    pub fn dropdown_visible(&self) -> bool {
+        use winapi::um::winuser::CB_GETDROPPEDSTATE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, CB_GETDROPPEDSTATE, 0, 0) != 0
+    }
+
+    /// Show or hide the dropdown of the combox.
+    /// `OnComboBoxDropdown` and `OnComboBoxClosed` are raised as if the user opened or closed the dropdown.
     pub fn dropdown(&self, v: bool) {
         use winapi::um::winuser::CB_SHOWDROPDOWN;
     
@@ -154,7 +179,6 @@ impl<D: Display+Default> ComboBox<D> {
     /// Return `None` if no item is selected. This reads the visual value.
     pub fn selection_string(&self) -> Option<String> {
         use winapi::um::winuser::{CB_GETCURSEL, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR};
-        use winapi::shared::ntdef::WCHAR;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
@@ -164,16 +188,26 @@ impl<D: Display+Default> ComboBox<D> {
         else {
             let index = index as usize;
             let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1; // +1 for the null character
-            let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
-            unsafe { 
-                buffer.set_len(length); 
-                wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_ptr() as LPARAM);
-            }
+            let mut buffer = WideBuffer::new(length);
+            wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_lparam());
 
-            Some(from_utf16(&buffer))
+            Some(buffer.to_string())
         }
     }
 
+    /// Return the text displayed in the selection field. With the `EDITABLE` or `SIMPLE` flag, this is the text typed by the user,
+    /// which may not be in the collection. Use `selection` to get the index of the selected item.
+    pub fn text(&self) -> String {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_text(handle) }
+    }
+
+    /// Set the text displayed in the selection field. Only works with the `EDITABLE` or `SIMPLE` flag. Does not change the selection.
+    pub fn set_text<'a>(&self, v: &'a str) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_text(handle, v) }
+    }
+
     /// Set the currently selected item in the combobox.
     /// Does nothing if the index is out of bound
     /// If the value is None, remove the selected value
@@ -194,9 +228,32 @@ impl<D: Display+Default> ComboBox<D> {
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         
-        let os_string = to_utf16(value);
+        let mut os_string = WideBuffer::from_str(value);
+
+        let index = wh::send_message(handle, CB_SELECTSTRING, 0, os_string.as_lparam());
+        if index == CB_ERR {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
 
-        let index = wh::send_message(handle, CB_SELECTSTRING, 0, os_string.as_ptr() as LPARAM);
+    /// Search an item that begins by the value (or that is equal to the value if `exact` is true) without changing the selection.
+    /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
+    /// Return the index of the first item found or None if the search was not successful
+    pub fn find_item(&self, value: &str, exact: bool) -> Option<usize> {
+        use winapi::um::winuser::{CB_FINDSTRING, CB_FINDSTRINGEXACT, CB_ERR};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut os_string = WideBuffer::from_str(value);
+
+        let msg = match exact {
+            true => CB_FINDSTRINGEXACT,
+            false => CB_FINDSTRING
+        };
+
+        // Start index of -1 searches the whole list from the beginning
+        let index = wh::send_message(handle, msg, usize::max_value(), os_string.as_lparam());
         if index == CB_ERR {
             None
         } else {
@@ -204,6 +261,37 @@ impl<D: Display+Default> ComboBox<D> {
         }
     }
 
+    /**
+        Return the value attached to the item at `index` with `set_item_data`. Return None if the index is out of range.
+        Items that never had a value attached return 0.
+    */
+    pub fn item_data(&self, index: usize) -> Option<usize> {
+        use winapi::um::winuser::CB_GETITEMDATA;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match index < self.len() {
+            true => Some(wh::send_message(handle, CB_GETITEMDATA, index as WPARAM, 0) as usize),
+            false => None
+        }
+    }
+
+    /**
+        Attach a pointer sized value to the item at `index`. Ex: the database key of the item.
+        Does nothing if the index is out of range.
+
+        The value is stored by the system control, not in the rust collection. It is lost when the item is removed
+        or when the items are rebuilt from the collection (ex: `sync`, `set_collection`, `sort`). If the value is a pointer,
+        the control does not free it.
+    */
+    pub fn set_item_data(&self, index: usize, data: usize) {
+        use winapi::um::winuser::CB_SETITEMDATA;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if index < self.len() {
+            wh::send_message(handle, CB_SETITEMDATA, index as WPARAM, data as LPARAM);
+        }
+    }
+
     /// Add a new item to the combobox. Sort the collection if the combobox is sorted.
     pub fn push(&self, item: D) {
         use winapi::um::winuser::CB_ADDSTRING;
@@ -211,9 +299,9 @@ impl<D: Display+Default> ComboBox<D> {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
         let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+        let mut display_os = WideBuffer::from_str(&display);
 
-        wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+        wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_lparam());
 
         self.collection.borrow_mut().push(item);
     }
@@ -228,7 +316,7 @@ impl<D: Display+Default> ComboBox<D> {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
         let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+        let mut display_os = WideBuffer::from_str(&display);
 
         let mut col = self.collection.borrow_mut();
         if index == std::usize::MAX {
@@ -237,39 +325,46 @@ impl<D: Display+Default> ComboBox<D> {
             col.insert(index, item);
         }
 
-        wh::send_message(handle, CB_INSERTSTRING, index, display_os.as_ptr() as LPARAM);
+        wh::send_message(handle, CB_INSERTSTRING, index, display_os.as_lparam());
     }
 
     /// Update the visual of the control with the inner collection.
     /// This rebuild every item in the combobox and can take some time on big collections.
+    /// The control is only repainted once all the items are added.
     pub fn sync(&self) {
         use winapi::um::winuser::CB_ADDSTRING;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
-        self.clear_inner(handle);
+        wh::without_redraw(handle, || {
+            self.clear_inner(handle);
 
-        for item in self.collection.borrow().iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
-        }
+            for item in self.collection.borrow().iter() {
+                let display = format!("{}", item);
+                let mut display_os = WideBuffer::from_str(&display);
+                
+                wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_lparam());
+            }
+        });
     }
 
     /// Set the item collection of the combobox. Return the old collection
+    /// The control is only repainted once all the items are added.
+    /// To add many items with `push` or `insert`, wrap the calls in `handle.set_redraw(false)` and `handle.set_redraw(true)`.
     pub fn set_collection(&self, mut col: Vec<D>) -> Vec<D> {
         use winapi::um::winuser::CB_ADDSTRING;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
-        self.clear_inner(handle);
+        wh::without_redraw(handle, || {
+            self.clear_inner(handle);
 
-        for item in col.iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
-        }
+            for item in col.iter() {
+                let display = format!("{}", item);
+                let mut display_os = WideBuffer::from_str(&display);
+                wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_lparam());
+            }
+        });
 
         let mut col_ref = self.collection.borrow_mut();
         mem::swap::<Vec<D>>(&mut col_ref, &mut col);
@@ -382,6 +477,101 @@ impl<D: Display+Default> ComboBox<D> {
         self.collection.borrow_mut()
     }
 
+    /// Return the width of the dropdown list in pixels. By default, this is the width of the control.
+    pub fn dropdown_width(&self) -> u32 {
+        use winapi::um::winuser::CB_GETDROPPEDWIDTH;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, CB_GETDROPPEDWIDTH, 0, 0) as u32
+    }
+
+    /// Set the minimum width of the dropdown list in pixels. The list is never narrower than the control.
+    pub fn set_dropdown_width(&self, width: u32) {
+        use winapi::um::winuser::CB_SETDROPPEDWIDTH;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, CB_SETDROPPEDWIDTH, width as WPARAM, 0);
+    }
+
+    /**
+        Resize the dropdown list so that its widest item is not clipped, using the control font. Returns the new width in pixels.
+        The list is never narrower than the control.
+
+        If `max_width` is set and the widest item does not fit, the list width is set to `max_width` and the list can be scrolled
+        horizontally. The scrollbar is only displayed if the combobox was created with the `H_SCROLL` flag.
+
+        Call this method again after the items were changed.
+    */
+    pub fn fit_dropdown_width(&self, max_width: Option<u32>) -> u32 {
+        use winapi::um::winuser::{CB_GETLBTEXT, CB_GETLBTEXTLEN, CB_SETHORIZONTALEXTENT, CB_ERR, SM_CXVSCROLL, SM_CXEDGE};
+        use winapi::um::winuser::{GetDC, ReleaseDC, GetSystemMetrics};
+        use winapi::um::wingdi::{SelectObject, GetTextExtentPoint32W};
+        use winapi::shared::windef::{HGDIOBJ, SIZE};
+
+        const TEXT_PADDING: i32 = 8;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut widest = 0;
+        unsafe {
+            let dc = GetDC(handle);
+            let old = SelectObject(dc, wh::get_window_font(handle) as HGDIOBJ);
+
+            for i in 0..self.len() {
+                let length = wh::send_message(handle, CB_GETLBTEXTLEN, i as WPARAM, 0);
+                if length == CB_ERR {
+                    continue;
+                }
+
+                let mut buffer: Vec<u16> = vec![0; (length as usize) + 1];
+                let length = wh::send_message(handle, CB_GETLBTEXT, i as WPARAM, buffer.as_mut_ptr() as LPARAM);
+                if length == CB_ERR {
+                    continue;
+                }
+
+                let mut size = SIZE { cx: 0, cy: 0 };
+                GetTextExtentPoint32W(dc, buffer.as_ptr(), length as i32, &mut size);
+                widest = widest.max(size.cx);
+            }
+
+            SelectObject(dc, old);
+            ReleaseDC(handle, dc);
+        }
+
+        let extent = widest + TEXT_PADDING;
+        let border = unsafe { GetSystemMetrics(SM_CXVSCROLL) + (GetSystemMetrics(SM_CXEDGE) * 2) };
+        let mut width = (extent + border) as u32;
+
+        match max_width {
+            Some(max) if width > max => {
+                width = max;
+                wh::send_message(handle, CB_SETHORIZONTALEXTENT, extent as WPARAM, 0);
+            },
+            _ => {
+                wh::send_message(handle, CB_SETHORIZONTALEXTENT, 0, 0);
+            }
+        }
+
+        self.set_dropdown_width(width);
+        self.dropdown_width()
+    }
+
+    /// Return the height of the items in the dropdown list
+    pub fn item_height(&self) -> u32 {
+        use winapi::um::winuser::CB_GETITEMHEIGHT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, CB_GETITEMHEIGHT, 0, 0) as u32
+    }
+
+    /// Set the height of the items in the dropdown list. Mostly useful with the `OWNER_DRAW` flag.
+    pub fn set_item_height(&self, height: u32) {
+        use winapi::um::winuser::CB_SETITEMHEIGHT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, CB_SETITEMHEIGHT, 0, height as LPARAM);
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "COMBOBOX"
@@ -404,10 +594,61 @@ impl<D: Display+Default> ComboBox<D> {
         wh::send_message(handle, CB_RESETCONTENT, 0, 0);
     }
 
+    /// Raise `OnComboBoxSubmit` when ENTER is pressed in the edit field of an editable combobox
+    fn hook_submit(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::NWG_SUBMIT;
+        use winapi::um::winuser::{GetComboBoxInfo, COMBOBOXINFO, PostMessageW, WM_KEYDOWN, WM_CHAR, WM_GETDLGCODE, VK_RETURN, DLGC_WANTALLKEYS, CB_GETDROPPEDSTATE, MSG};
+        use winapi::um::commctrl::DefSubclassProc;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let edit = unsafe {
+            let mut info: COMBOBOXINFO = mem::zeroed();
+            info.cbSize = mem::size_of::<COMBOBOXINFO>() as u32;
+            if GetComboBoxInfo(handle, &mut info) == 0 || info.hwndItem.is_null() {
+                return;
+            }
+            info.hwndItem
+        };
+
+        // ENTER selects the highlighted item when the dropdown list is open
+        let dropped = move || wh::send_message(handle, CB_GETDROPPEDSTATE, 0, 0) != 0;
+
+        let handler = bind_raw_event_handler_inner(&ControlHandle::Hwnd(edit), 0, move |hwnd, msg, w, l| {
+            match msg {
+                WM_GETDLGCODE if l != 0 => unsafe {
+                    // Dialog navigation would eat the ENTER key before it reaches the edit field
+                    let key = &*(l as *const MSG);
+                    match key.message == WM_KEYDOWN && key.wParam == VK_RETURN as WPARAM && !dropped() {
+                        true => Some(DefSubclassProc(hwnd, msg, w, l) | DLGC_WANTALLKEYS as isize),
+                        false => None
+                    }
+                },
+                WM_KEYDOWN if w == VK_RETURN as WPARAM && !dropped() => {
+                    unsafe { PostMessageW(handle, NWG_SUBMIT, 0, 0); }
+                    Some(0)
+                },
+                // Prevents the beep of single line edit controls
+                WM_CHAR if w == 0x0D && !dropped() => Some(0),
+                _ => None
+            }
+        });
+
+        *self.handler0.borrow_mut() = Some(handler.unwrap());
+    }
+
 }
 
 impl<D: Display+Default> Drop for ComboBox<D> {
     fn drop(&mut self) {
+        use crate::unbind_raw_event_handler;
+
+        let handler = self.handler0.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
     }
 }
@@ -422,6 +663,7 @@ pub struct ComboBoxBuilder<'a, D: Display+Default> {
     font: Option<&'a Font>,
     collection: Option<Vec<D>>,
     selected_index: Option<usize>,
+    item_height: Option<u32>,
     parent: Option<ControlHandle>
 }
 
@@ -477,6 +719,11 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
         self
     }
 
+    pub fn item_height(mut self, height: Option<u32>) -> ComboBoxBuilder<'a, D> {
+        self.item_height = height;
+        self
+    }
+
 
     pub fn build(self, out: &mut ComboBox<D>) -> Result<(), NwgError> {
         let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
@@ -486,12 +733,19 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
             None => Err(NwgError::no_parent("ComboBox"))
         }?;
 
+        // Editable comboboxes use the simple or dropdown style instead of the dropdown list style
+        let mut forced_flags = out.forced_flags();
+        match flags & CBS_DROPDOWNLIST {
+            CBS_SIMPLE | CBS_DROPDOWN => { forced_flags &= !CBS_DROPDOWNLIST; },
+            _ => {}
+        }
+
         // Drop the old object
         *out = ComboBox::default();
 
         out.handle = ControlBase::build_hwnd()
             .class_name(out.class_name())
-            .forced_flags(out.forced_flags())
+            .forced_flags(forced_flags)
             .flags(flags)
             .ex_flags(self.ex_flags)
             .size(self.size)
@@ -505,6 +759,14 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
             out.set_font(Font::global_default().as_ref());
         }
 
+        if let Some(height) = self.item_height {
+            out.set_item_height(height);
+        }
+
+        if forced_flags & CBS_DROPDOWNLIST != CBS_DROPDOWNLIST {
+            out.hook_submit();
+        }
+
         if self.collection.is_some() {
             out.set_collection(self.collection.unwrap());
         }
@@ -1,7 +1,9 @@
-use winapi::shared::minwindef::DWORD;
+use winapi::shared::minwindef::{DWORD, WPARAM, LPARAM};
 use winapi::shared::windef::{HWND};
 use super::ControlHandle;
 use crate::win32::window::{build_hwnd_control, build_timer, build_notice};
+use crate::win32::base_helper::{to_utf16, from_utf16};
+use std::fmt;
 use crate::{NwgError};
 
 #[cfg(feature = "menu")] use crate::win32::menu::build_hmenu_control;
@@ -285,3 +287,52 @@ impl OtherBuilder {
     }
 
 }
+
+/**
+    An owned utf16 buffer that is passed to the winapi as a message parameter.
+
+    Use `WideBuffer::new` to allocate a zeroed buffer that will be filled by a message (ex: `LB_GETTEXT`)
+    and `WideBuffer::from_str` to send a null terminated string (ex: `LB_ADDSTRING`). The buffer must outlive the message.
+*/
+pub struct WideBuffer(Vec<u16>);
+
+impl WideBuffer {
+
+    /// Allocate a buffer of `length` null characters. Include the terminating null character in `length`.
+    pub fn new(length: usize) -> WideBuffer {
+        WideBuffer(vec![0; length])
+    }
+
+    /// Encode a null terminated string
+    pub fn from_str<'a>(s: &'a str) -> WideBuffer {
+        WideBuffer(to_utf16(s))
+    }
+
+    /// The size of the buffer in characters, including the null characters
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The pointer to the buffer, as a message WPARAM. The pointer is mutable because the message may write into the buffer.
+    pub fn as_wparam(&mut self) -> WPARAM {
+        self.0.as_mut_ptr() as WPARAM
+    }
+
+    /// The pointer to the buffer, as a message LPARAM. The pointer is mutable because the message may write into the buffer.
+    pub fn as_lparam(&mut self) -> LPARAM {
+        self.0.as_mut_ptr() as LPARAM
+    }
+
+    /// The characters of the buffer up to the first null character, or the whole buffer if there is none
+    pub fn as_slice(&self) -> &[u16] {
+        let null_index = self.0.iter().position(|&i| i==0).unwrap_or(self.0.len());
+        &self.0[0..null_index]
+    }
+
+}
+
+impl fmt::Display for WideBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", from_utf16(self.as_slice()))
+    }
+}
@@ -1,6 +1,32 @@
 use winapi::shared::windef::{HWND, HMENU};
+use winapi::um::winuser::{WS_CHILD, WS_POPUP};
 use crate::win32::window_helper as wh;
+use crate::win32::base_helper::check_hwnd;
+use crate::win32::accessibility;
+use crate::{NwgError, Coords, Event, MousePressEvent};
 
+const NOT_BOUND: &'static str = "Control is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "Control handle is not HWND!";
+
+/// Styles that are only read by the system when the window is created.
+/// The low word of a style is specific to the control class and cannot be safely toggled generically.
+const CREATION_ONLY_STYLES: u32 = WS_CHILD | WS_POPUP | 0xFFFF;
+
+
+/**
+    The pixels of a control returned by `ControlHandle::capture`
+*/
+#[derive(Clone, Debug)]
+pub struct ControlCapture {
+    /// Width of the image in pixels
+    pub width: u32,
+
+    /// Height of the image in pixels
+    pub height: u32,
+
+    /// The pixels, row by row from the top left corner. Each pixel is 4 bytes in the BGRA order. Alpha is always 255.
+    pub pixels: Vec<u8>,
+}
 
 /**
     Inner handle type used internally by each control.
@@ -32,8 +58,16 @@ pub enum ControlHandle {
 
 impl ControlHandle {
 
-    /// Destroy the underlying object and set the handle to `NoHandle`
-    /// Can be used to "reset" a UI component
+    /**
+        Destroy the underlying object and set the handle to `NoHandle`
+        Can be used to "reset" a UI component
+
+        Controls are also destroyed when their value is dropped, so dynamic controls (ex: rows of controls added at runtime)
+        can be removed by dropping them or by calling `destroy` on their handle.
+
+        The children of a destroyed window are not destroyed with it. They are hidden and detached from the window,
+        and each child is destroyed when its own value is dropped. This way, the rust value of a child never points to a freed window.
+    */
     pub fn destroy(&mut self) {
         match self {
             &mut ControlHandle::Hwnd(h) => wh::destroy_window(h),
@@ -99,6 +133,674 @@ impl ControlHandle {
         }
     }
 
+    /**
+        Return the text of any control, without knowing its type. Ex: to save the values of a form.
+        This is the same value as the `text` method of the controls that have one: the text of a `TextInput`,
+        the label of a `Button` or `CheckBox`, the title of a `Window`, etc.
+
+        Controls without text (ex: `ProgressBar`) and handles that are not windows (ex: menu items, timers) return an empty string.
+    */
+    pub fn text(&self) -> String {
+        match self.hwnd() {
+            Some(handle) => unsafe { wh::get_window_text(handle) },
+            None => String::new()
+        }
+    }
+
+    /**
+        Set the text of any control, without knowing its type. See `text`.
+        Does nothing if the handle is not a window handle.
+
+        Unlike the `set_text` method of the controls, a failure is reported: returns a `NwgError::Win32Error`
+        if the system refused the text (ex: the control was destroyed).
+    */
+    pub fn set_text(&self, text: &str) -> Result<(), NwgError> {
+        use winapi::um::winuser::SetWindowTextW;
+        use crate::win32::base_helper::to_utf16;
+
+        if let Some(handle) = self.hwnd() {
+            let text = to_utf16(text);
+            if unsafe { SetWindowTextW(handle, text.as_ptr()) } == 0 {
+                return Err(NwgError::last_win32_error("SetWindowTextW"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+        Return the direct children of the control, in tab order (the creation order unless `set_tab_order` was called).
+        Returns an empty vector (without allocating) if the control has no children or if the handle is not a window handle.
+
+        Use `class_name` to find the children of a given type. Ex: all the `TextInput` of a form have the `Edit` class.
+    */
+    pub fn children(&self) -> Vec<ControlHandle> {
+        let mut children = Vec::new();
+        if let Some(handle) = self.hwnd() {
+            wh::iterate_window_children(handle, |child| children.push(ControlHandle::Hwnd(child)));
+        }
+
+        children
+    }
+
+    /**
+        Return the control that has the keyboard focus if it is this control or one of its descendants (ex: the focused field of a window).
+        Returns `None` if the focus is outside of this control, if no control has the focus, or if the handle is not a window handle.
+
+        The edit box of an editable combo box is reported as the combo box itself, so the value can be compared to the handles of the controls.
+
+        ```rust
+        use native_windows_gui as nwg;
+        fn make_bold(window: &nwg::Window, name: &nwg::TextInput) {
+            if window.handle.focused_child() == Some(name.handle) {
+                // ...
+            }
+        }
+        ```
+    */
+    pub fn focused_child(&self) -> Option<ControlHandle> {
+        use winapi::um::winuser::{GetFocus, IsChild};
+
+        let handle = self.hwnd()?;
+        let mut focus = unsafe { GetFocus() };
+        if focus.is_null() || (focus != handle && unsafe { IsChild(handle, focus) } == 0) {
+            return None;
+        }
+
+        if focus != handle {
+            let parent = wh::get_window_parent(focus);
+            if unsafe { wh::get_window_class_name(parent) }.eq_ignore_ascii_case("ComboBox") {
+                focus = parent;
+            }
+        }
+
+        Some(ControlHandle::Hwnd(focus))
+    }
+
+    /**
+        Return the name of the win32 class of the control. Ex: `Button`, `Edit`, `ComboBox`, `NativeWindowsGuiWindow`.
+        Some controls share the same class (ex: `Button`, `CheckBox` and `RadioButton` are all `Button`).
+        Returns an empty string if the handle is not a window handle.
+    */
+    pub fn class_name(&self) -> String {
+        match self.hwnd() {
+            Some(handle) => unsafe { wh::get_window_class_name(handle) },
+            None => String::new()
+        }
+    }
+
+    /// Return the window styles (`GWL_STYLE`) of the control.
+    /// Panics if the handle is not a window handle.
+    pub fn style(&self) -> u32 {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle)
+    }
+
+    /**
+        Add window styles to the control at runtime and redraw its frame. Ex: `handle.add_style(WS_BORDER)`
+
+        Only the generic window styles (`WS_*`, the high word of the style) can be changed this way.
+        `WS_CHILD`, `WS_POPUP` and the control specific styles (`ES_*`, `BS_*`, etc. the low word of the style)
+        are only read when the control is created. Use the methods of the control instead (ex: `TextInput::set_readonly`).
+        For those styles, this function returns a `NwgError::NotSupported`.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn add_style(&self, style: u32) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        check_runtime_style(style)?;
+
+        wh::set_style(handle, wh::get_style(handle) | style);
+        unsafe { wh::refresh_frame(handle); }
+
+        Ok(())
+    }
+
+    /**
+        Remove window styles from the control at runtime and redraw its frame. Ex: `handle.remove_style(WS_BORDER)`
+        See `add_style` for the styles that cannot be changed this way.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn remove_style(&self, style: u32) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        check_runtime_style(style)?;
+
+        wh::set_style(handle, wh::get_style(handle) & !style);
+        unsafe { wh::refresh_frame(handle); }
+
+        Ok(())
+    }
+
+    /**
+        Send a message to the control and return the result. This is an escape hatch for the messages that NWG does not wrap
+        (ex: `EM_SETTABSTOPS` on a text box). The call returns once the control processed the message.
+
+        # Safety
+        The message is sent as is. Wrong parameters can corrupt the control or crash the application.
+        When a parameter is a pointer, the buffer must stay alive and valid for the whole call.
+
+        Panics if the handle is not a window handle.
+
+        ```rust
+        use native_windows_gui as nwg;
+        fn set_tab_stops(text: &nwg::TextBox) {
+            const EM_SETTABSTOPS: u32 = 0x00CB;
+            let stops: [u32; 1] = [16];
+            unsafe { text.handle.send_message(EM_SETTABSTOPS, 1, stops.as_ptr() as isize); }
+        }
+        ```
+    */
+    pub unsafe fn send_message(&self, msg: u32, w: usize, l: isize) -> isize {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, msg, w, l)
+    }
+
+    /**
+        Post a message in the message queue of the control and return without waiting for the control to process it.
+
+        # Safety
+        Same as `send_message`. Because the call returns immediately, a pointer parameter must stay valid until the
+        message is processed, so it usually cannot point to a local buffer.
+
+        Panics if the handle is not a window handle.
+    */
+    pub unsafe fn post_message(&self, msg: u32, w: usize, l: isize) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        wh::post_message(handle, msg, w, l)
+    }
+
+    /**
+        Move a child control into another parent window. Ex: move a button from one frame to another.
+
+        Returns a `NwgError::NotSupported` if:
+          * The control is not a child control (`WS_CHILD`). Top level windows cannot be reparented.
+          * `parent` is the control itself or one of its children, as this would create a cycle in the window tree.
+          * `parent` was created by another thread.
+
+        Returns a `NwgError::Win32Error` if the system refused to change the parent.
+
+        The checks are done before the parent is changed. The control keeps its position relative to its parent.
+        The events of the control are only received if the new parent is in the same top level window as the old one.
+
+        Panics if the handle or the parent handle is not a window handle.
+    */
+    pub fn set_parent<C: Into<ControlHandle>>(&self, parent: C) -> Result<(), NwgError> {
+        use winapi::um::winuser::{SetParent, GetWindowThreadProcessId};
+        use std::ptr;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        let parent = check_hwnd(&parent.into(), NOT_BOUND, BAD_HANDLE);
+
+        if wh::get_style(handle) & WS_CHILD != WS_CHILD {
+            return Err(NwgError::not_supported("Only child controls can be moved into another parent"));
+        }
+
+        let mut ancestor = parent;
+        while !ancestor.is_null() {
+            if ancestor == handle {
+                return Err(NwgError::not_supported("A control cannot be moved into itself or into one of its children"));
+            }
+            ancestor = wh::get_window_parent(ancestor);
+        }
+
+        unsafe {
+            let thread = GetWindowThreadProcessId(handle, ptr::null_mut());
+            let parent_thread = GetWindowThreadProcessId(parent, ptr::null_mut());
+            if thread != parent_thread {
+                return Err(NwgError::not_supported("A control cannot be moved into a window created by another thread"));
+            }
+
+            if SetParent(handle, parent).is_null() {
+                return Err(NwgError::last_win32_error("SetParent"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+        Move and resize many controls at once. Each item is `(control, x, y, width, height)`, in the coordinates of the parent of the control.
+        Unlike calling `set_position` and `set_size` on each control, the controls are repainted once after they were all moved.
+        This is what layouts use to place their children.
+
+        All the controls should have the same parent. Panics if one of the handles is not a window handle.
+    */
+    pub fn set_bounds_many(bounds: &[(ControlHandle, i32, i32, u32, u32)]) {
+        let bounds: Vec<_> = bounds.iter()
+            .map(|&(control, x, y, w, h)| (check_hwnd(&control, NOT_BOUND, BAD_HANDLE), x, y, w, h))
+            .collect();
+
+        unsafe { wh::set_windows_bounds(&bounds); }
+    }
+
+    /**
+        Set the order in which the keyboard focus moves between the children of the control when the user presses `Tab`.
+        By default, the tab order is the creation order of the controls.
+
+        `controls` must be direct children of the control. They are moved at the top of the z-order, in the same order
+        as the slice, and receive the `WS_TABSTOP` style. The children that are not in the slice come after them.
+        Returns a `NwgError::NotSupported` if one of the controls is not a direct child. In this case, nothing is changed.
+
+        Panics if the handle or one of the controls is not a window handle.
+    */
+    pub fn set_tab_order(&self, controls: &[ControlHandle]) -> Result<(), NwgError> {
+        use winapi::um::winuser::WS_TABSTOP;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+
+        let mut children = Vec::with_capacity(controls.len());
+        for control in controls {
+            let child = check_hwnd(control, NOT_BOUND, BAD_HANDLE);
+            if wh::get_window_parent(child) != handle {
+                return Err(NwgError::not_supported("The tab order can only include the direct children of a control"));
+            }
+            children.push(child);
+        }
+
+        let mut previous = None;
+        for child in children {
+            unsafe { wh::set_window_after(child, previous); }
+            wh::set_style(child, wh::get_style(child) | WS_TABSTOP);
+            previous = Some(child);
+        }
+
+        Ok(())
+    }
+
+    /**
+        Render the control and its children into an image. Ex: to compare a custom drawn control with a reference image in a test.
+        The image has the physical size of the control, including its borders and the title bar of windows.
+
+        Hidden controls can be captured, but some controls (ex: controls rendered with OpenGL) only render when they are visible.
+        Returns a `NwgError::NotSupported` if the control cannot be rendered.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn capture(&self) -> Result<ControlCapture, NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        match unsafe { wh::capture_window(handle) } {
+            Some((width, height, pixels)) => Ok(ControlCapture { width, height, pixels }),
+            None => Err(NwgError::not_supported("The control could not be rendered"))
+        }
+    }
+
+    /// Return `true` if the control and its children are painted with double buffering (`WS_EX_COMPOSITED`).
+    /// Panics if the handle is not a window handle.
+    pub fn double_buffered(&self) -> bool {
+        use winapi::um::winuser::WS_EX_COMPOSITED;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        wh::get_ex_style(handle) & WS_EX_COMPOSITED == WS_EX_COMPOSITED
+    }
+
+    /**
+        Enable or disable double buffering (`WS_EX_COMPOSITED`) for the control and all its children.
+        The system paints the controls in an offscreen buffer and copies the result in one go, which removes most of the flickering on resize.
+        This is usually set on the top level window. `OnPaint` handlers do not need to be changed.
+
+        Double buffering does not work with controls that render with OpenGL or DirectX.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_double_buffered(&self, v: bool) {
+        use winapi::um::winuser::WS_EX_COMPOSITED;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        let ex_style = wh::get_ex_style(handle);
+        match v {
+            true => wh::set_ex_style(handle, ex_style | WS_EX_COMPOSITED),
+            false => wh::set_ex_style(handle, ex_style & !WS_EX_COMPOSITED)
+        }
+
+        unsafe { wh::refresh_frame(handle); }
+    }
+
+    /**
+        Enable or disable the painting of the control and its children (`WM_SETREDRAW`).
+        When painting is enabled again, the control and all its children are repainted in one go.
+
+        Use this to batch a lot of changes to a window (ex: filling a form, showing or moving many controls)
+        without the user seeing each control being updated one at a time. Calls are not counted, so a single `set_redraw(true)`
+        will restore the painting.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_redraw(&self, v: bool) {
+        use winapi::um::winuser::{WM_SETREDRAW, RDW_ERASE, RDW_FRAME, RDW_INVALIDATE, RDW_ALLCHILDREN, RedrawWindow};
+        use winapi::shared::minwindef::WPARAM;
+        use std::ptr;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, WM_SETREDRAW, v as WPARAM, 0);
+
+        if v {
+            unsafe { RedrawWindow(handle, ptr::null(), ptr::null_mut(), RDW_ERASE | RDW_FRAME | RDW_INVALIDATE | RDW_ALLCHILDREN); }
+        }
+    }
+
+    /**
+        Show or hide all the direct children of the control with a single repaint.
+
+        This is meant to be used with controls built without the `VISIBLE` flag: create all the controls of a form hidden
+        and then call `set_children_visible(true)` on the parent once everything is built. Controls nested in a child container
+        (ex: a `Frame`) are not affected, call this method on the container too.
+
+        Windows are created with `WS_CLIPCHILDREN` so that the parent background is not painted over its children.
+        For other containers, use `add_style(WS_CLIPCHILDREN)` to avoid repainting the parent when many children are updated.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_children_visible(&self, v: bool) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+
+        self.set_redraw(false);
+        wh::iterate_window_children(handle, |child| unsafe { wh::set_window_visibility(child, v) });
+        self.set_redraw(true);
+    }
+
+    /**
+        Return the position of the upper left corner of the control, in the coordinate system of `coords`.
+        Unlike the `position` method of the controls, the origin is explicit: with `Coords::Parent`, the position of
+        a top level window (including popups owned by another window) is its screen position.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn position_in(&self, coords: Coords) -> (i32, i32) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_position_in(handle, coords == Coords::Screen) }
+    }
+
+    /**
+        Move the upper left corner of the control to `(x, y)`, in the coordinate system of `coords`.
+        Ex: use `Coords::Screen` to place a child control under the mouse cursor returned by `GlobalCursor::position`.
+
+        Returns a `NwgError::Win32Error` if `SetWindowPos` fails.
+        Panics if the handle is not a window handle or if the control was destroyed.
+    */
+    pub fn set_position_in(&self, coords: Coords, x: i32, y: i32) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        match unsafe { wh::set_window_position_in(handle, x, y, coords == Coords::Screen) } {
+            0 => Err(NwgError::last_win32_error("SetWindowPos")),
+            _ => Ok(())
+        }
+    }
+
+    /**
+        Return the position and the size of the control as `(x, y, width, height)`, with the position in the coordinate system of `coords`.
+        The values are read at once, so they always describe the same state of the control.
+
+        The size is the size of the window rectangle, borders included. For most controls, this is the value of `size`. For a top level
+        window, this includes the title bar and the borders, unlike `Window::size` that only returns the client area.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn bounds_in(&self, coords: Coords) -> (i32, i32, u32, u32) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_bounds_in(handle, coords == Coords::Screen) }
+    }
+
+    /**
+        Move and resize the control in a single call, with the position in the coordinate system of `coords`.
+        Unlike calling `set_position` and `set_size`, the control is moved and repainted once.
+        The size is the size of the window rectangle, borders included (see `bounds_in`).
+
+        To move many controls at once, use `ControlHandle::set_bounds_many`.
+
+        Returns a `NwgError::Win32Error` if `SetWindowPos` fails.
+        Panics if the handle is not a window handle or if the control was destroyed.
+    */
+    pub fn set_bounds_in(&self, coords: Coords, x: i32, y: i32, width: u32, height: u32) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        match unsafe { wh::set_window_bounds_in(handle, x, y, width, height, coords == Coords::Screen) } {
+            0 => Err(NwgError::last_win32_error("SetWindowPos")),
+            _ => Ok(())
+        }
+    }
+
+    /**
+        Convert a point in screen coordinates to the client coordinates of the control.
+        Panics if the handle is not a window handle.
+    */
+    pub fn screen_to_client(&self, x: i32, y: i32) -> (i32, i32) {
+        use winapi::um::winuser::ScreenToClient;
+        use winapi::shared::windef::POINT;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        let mut point = POINT { x, y };
+        unsafe { ScreenToClient(handle, &mut point); }
+
+        (point.x, point.y)
+    }
+
+    /**
+        Return `true` if the point is inside the client area of the control. The borders, the title bar and the scrollbars are excluded.
+        `x` and `y` are in **screen** coordinates, like the values returned by `GlobalCursor::position`.
+
+        Overlapping controls are not considered: the point may be inside the control even if another control is drawn over it.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn hit_test(&self, x: i32, y: i32) -> bool {
+        use winapi::um::winuser::GetClientRect;
+        use winapi::shared::windef::RECT;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        let (x, y) = self.screen_to_client(x, y);
+
+        let mut rect = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        unsafe { GetClientRect(handle, &mut rect); }
+
+        x >= rect.left && x < rect.right && y >= rect.top && y < rect.bottom
+    }
+
+    /**
+        Scroll the parent of the control by the smallest amount needed to show the whole control. The scroll position is clamped
+        to the parent scroll range. If the control is larger than its parent, its upper left corner is shown.
+        Ex: to show the section of a long form that matches the item selected in a tree view.
+
+        The parent must have the `WS_VSCROLL` and/or `WS_HSCROLL` styles, a scroll range in pixels, and its content is moved
+        with `ScrollWindowEx`. Applications that keep their own scroll position must read it back from the scrollbar.
+
+        Returns `false` if the control was already visible or if the parent cannot be scrolled.
+        Panics if the handle is not a window handle.
+    */
+    pub fn ensure_visible(&self) -> bool {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::scroll_into_view(handle) }
+    }
+
+    /**
+        Return the height needed to display the text of the control word wrapped in `width` pixels.
+        The control font, borders, scrollbars and text margins are included, so the value can be used directly with `set_size`.
+        Ex: to grow a multiline label or text box to fit its content.
+
+        The value is computed for the current text. Call this again after the text or the font changed.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn preferred_height(&self, width: u32) -> u32 {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_preferred_height(handle, width as i32) as u32 }
+    }
+
+    /**
+        Temporarily paint the background of the control with `color`. Ex: to draw the attention of the user on an invalid field.
+        The background is restored after `duration` milliseconds, or when `clear_highlight` is called.
+
+        Highlighting a control that is already highlighted replaces the color and restarts the delay,
+        so repeated validations never leave a control stuck in the highlighted color.
+
+        This works with the controls that let their parent choose their background: text inputs, text boxes, labels,
+        check boxes, radio buttons and list boxes. It does nothing on the other controls.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn highlight(&self, color: [u8; 3], duration: u32) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        crate::win32::highlight::highlight(handle, color, duration);
+    }
+
+    /**
+        Restore the background of a control highlighted with `highlight` before the delay expires.
+        Return `false` if the control was not highlighted.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn clear_highlight(&self) -> bool {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        crate::win32::highlight::clear_highlight(handle)
+    }
+
+    /// Return `true` if the control is drawn with the visual styles of the system.
+    /// Panics if the handle is not a window handle.
+    pub fn themed(&self) -> bool {
+        use winapi::um::uxtheme::GetWindowTheme;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { !GetWindowTheme(handle).is_null() }
+    }
+
+    /**
+        Remove the visual styles of the control (`false`) or restore them (`true`). An unthemed control is drawn in the
+        classic Windows style (ex: a flat gray button with a 3D border).
+
+        Removing the theme also removes the hover and pressed animations of the control: buttons are not highlighted
+        under the mouse anymore and are drawn as sunken when pressed.
+
+        Returns a `NwgError::NotSupported` if the visual styles are not enabled for the application
+        (ex: with the `no-styling` feature or if the user selected a classic theme).
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_themed(&self, themed: bool) -> Result<(), NwgError> {
+        use winapi::um::uxtheme::{SetWindowTheme, IsAppThemed, IsThemeActive};
+        use winapi::shared::winerror::S_OK;
+        use std::ptr;
+
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            if IsAppThemed() == 0 || IsThemeActive() == 0 {
+                return Err(NwgError::not_supported("Visual styles are not enabled for this application"));
+            }
+
+            // An empty theme name matches no theme. A null name restores the default theme.
+            let empty = [0u16];
+            let result = match themed {
+                true => SetWindowTheme(handle, ptr::null(), ptr::null()),
+                false => SetWindowTheme(handle, empty.as_ptr(), empty.as_ptr()),
+            };
+
+            match result {
+                S_OK => Ok(()),
+                code => Err(NwgError::not_supported(format!("Failed to change the theme of the control (HRESULT {:#X})", code)))
+            }
+        }
+    }
+
+    /**
+        Set the name reported to screen readers (MSAA / UI Automation) for this control.
+        If `name` is None, the control reports its default name.
+
+        By default, buttons and labels report their text. Text inputs and comboboxes report the text of the label
+        that comes right before them in the z-order, so creating a label just before its input is enough to associate them.
+        Use this method when a control has no visible label or when the visible text is not meaningful (ex: an icon button).
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_accessible_name(&self, name: Option<&str>) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        accessibility::set_accessible_name(handle, name)
+    }
+
+    /**
+        Set the `AutomationId` reported to UI Automation clients for this control. If `id` is None, the control reports its default id.
+        Ex: to let a test automation tool (WinAppDriver, FlaUI, ...) find a control created at runtime by a stable name.
+
+        The id is kept until the control is destroyed. Unlike the accessible name, it is never read by screen readers.
+
+        Panics if the handle is not a window handle.
+    */
+    pub fn set_automation_id(&self, id: Option<&str>) -> Result<(), NwgError> {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        accessibility::set_automation_id(handle, id)
+    }
+
+    /**
+        Return the events that can be raised for this control. The list depends on the handle type and, for window handles,
+        on the class of the window (ex: `OnTextInput` is only raised for edit controls, `OnInit` for top level windows).
+
+        The variants of `OnMousePress` are returned as `OnMousePress(MousePressEvent::MousePressLeftUp)`. See `supports_event`.
+    */
+    pub fn supported_events(&self) -> Vec<Event> {
+        use Event::*;
+
+        let press = OnMousePress(MousePressEvent::MousePressLeftUp);
+        match self {
+            &ControlHandle::NoHandle => Vec::new(),
+            &ControlHandle::Hwnd(hwnd) => hwnd_events(hwnd),
+            &ControlHandle::Menu(_, _) | &ControlHandle::PopMenu(_, _) => vec![OnMenuOpen, OnMenuEnter, OnMenuExit, OnMenuHover],
+            &ControlHandle::MenuItem(_, _) => vec![OnMenuItemSelected, OnMenuHover],
+            &ControlHandle::Notice(_, _) => vec![OnNotice],
+            &ControlHandle::Timer(_, _) => vec![OnTimerTick],
+            &ControlHandle::SystemTray(_) => vec![press, OnContextMenu, OnTrayNotificationShow, OnTrayNotificationHide,
+                OnTrayNotificationTimeout, OnTrayNotificationUserClose, OnTrayNotificationDoubleClick],
+        }
+    }
+
+    /// Return `true` if `evt` can be raised for this control. All the variants of `OnMousePress` are considered equal.
+    pub fn supports_event(&self, evt: Event) -> bool {
+        use std::mem::discriminant;
+        self.supported_events().iter().any(|e| discriminant(e) == discriminant(&evt))
+    }
+
+}
+
+/// Return the events that can be raised for the window `hwnd`
+fn hwnd_events(hwnd: HWND) -> Vec<Event> {
+    use winapi::um::commctrl::{WC_TREEVIEW, WC_LISTVIEW};
+    use Event::*;
+
+    // Generic events raised for every window
+    let mut events = vec![OnMousePress(MousePressEvent::MousePressLeftUp), OnMouseMove, OnMouseWheel, OnContextMenu, OnHelp,
+        OnFocus, OnFocusLost, OnEnabledChanged, OnPaint, OnDrawItem, OnChar, OnKeyPress, OnKeyRelease, OnResize, OnMove,
+        OnFileDrop, OnTooltipText];
+
+    if wh::get_style(hwnd) & WS_CHILD == 0 {
+        events.extend_from_slice(&[OnInit, OnWindowClose, OnResizeBegin, OnResizeEnd, OnWindowMaximize, OnWindowMinimize,
+            OnMinMaxInfo, OnShortcut, OnSystemColorsChanged]);
+    }
+
+    let class_name = unsafe { wh::get_window_class_name(hwnd) };
+    let class_events: &[Event] = match &class_name as &str {
+        "Button" => &[OnButtonClick, OnButtonDoubleClick],
+        "Static" => &[OnLabelClick, OnLabelDoubleClick, OnImageFrameClick, OnImageFrameDoubleClick],
+        "Edit" => &[OnTextInput, OnTextInputDebounced, OnValidationFailed],
+        "ComboBox" => &[OnComboBoxClosed, OnComboBoxDropdown, OnComboxBoxSelection, OnComboBoxTextChanged, OnComboBoxSubmit],
+        "ListBox" => &[OnListBoxDoubleClick, OnListBoxSelect, OnListBoxItemMoved],
+        "RICHEDIT50W" => &[OnRichTextBoxSelectionChanged],
+        "SysDateTimePick32" => &[OnDatePickerDropdown, OnDatePickerClosed, OnDatePickerChanged],
+        "SysTabControl32" => &[TabsContainerChanged, TabsContainerChanging],
+        "msctls_trackbar32" => &[TrackBarUpdated, OnHorizontalScroll, OnVerticalScroll],
+        "ScrollBar" => &[OnHorizontalScroll, OnVerticalScroll],
+        WC_TREEVIEW => &[OnTreeViewClick, OnTreeViewDoubleClick, OnTreeViewRightClick, OnTreeFocusLost, OnTreeFocus,
+            OnTreeItemDelete, OnTreeItemExpanded, OnTreeItemChanged, OnTreeItemSelectionChanged],
+        WC_LISTVIEW => &[OnListViewClear, OnListViewItemRemoved, OnListViewItemInsert, OnListViewItemActivated, OnListViewClick,
+            OnListViewRightClick, OnListViewDoubleClick, OnListViewColumnClick, OnListViewItemChanged, OnListViewFocus, OnListViewFocusLost],
+        _ => &[]
+    };
+
+    events.extend_from_slice(class_events);
+    events
+}
+
+
+fn check_runtime_style(style: u32) -> Result<(), NwgError> {
+    match style & CREATION_ONLY_STYLES {
+        0 => Ok(()),
+        bad => Err(NwgError::not_supported(format!("Styles {:#X} can only be set when the control is created", bad)))
+    }
 }
 
 
@@ -1,10 +1,11 @@
 use winapi::um::winuser::{WS_OVERLAPPEDWINDOW, WS_VISIBLE, WS_DISABLED, WS_MAXIMIZE, WS_MINIMIZE, WS_CAPTION,
-WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_CLIPCHILDREN, WS_CLIPSIBLINGS };
+WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_CLIPCHILDREN, WS_CLIPSIBLINGS, WS_TABSTOP };
 
 use crate::win32::base_helper::check_hwnd;
 use crate::win32::window_helper as wh;
 use crate::{NwgError, Icon};
 use super::{ControlBase, ControlHandle};
+use std::cell::Cell;
 
 const NOT_BOUND: &'static str = "ExternCanvas is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: ExternCanvas handle is not HWND!";
@@ -29,6 +30,7 @@ bitflags! {
 
         General flags:
         * VISIBLE: Show the window right away
+        * TAB_STOP: The canvas can be selected using tab navigation (children canvas only)
     */
     pub struct ExternCanvasFlags: u32 {
         const NONE = 0;
@@ -42,6 +44,7 @@ bitflags! {
         const MAXIMIZED = WS_MAXIMIZE;
         const MINIMIZED = WS_MINIMIZE;
         const RESIZABLE = WS_THICKFRAME | WS_MAXIMIZEBOX;
+        const TAB_STOP = WS_TABSTOP;
     }
 }
 
@@ -57,6 +60,10 @@ bitflags! {
     As a children control, resize and move events cannot be triggered and window parameters
     are not visible.
 
+    A children canvas that accepts the keyboard focus (see the `TAB_STOP` flag) should show it by calling `draw_focus_rect`
+    at the end of the `OnPaint` event. Like the native controls, the focus rectangle is only drawn when the user navigates with the keyboard.
+    The canvas is repainted when it gains or loses the focus, and when the keyboard cues are toggled.
+
     **Builder parameters:**
       * `flags`: The window flags. See `ExternCanvasFlags`
       * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
@@ -85,7 +92,8 @@ bitflags! {
 */
 #[derive(Default)]
 pub struct ExternCanvas {
-    pub handle: ControlHandle
+    pub handle: ControlHandle,
+    show_focus_rect: Cell<Option<bool>>,
 }
 
 impl ExternCanvas {
@@ -111,6 +119,52 @@ impl ExternCanvas {
         unsafe { InvalidateRect(handle, ptr::null(), 1); }
     }
 
+    /// Return `true` if `draw_focus_rect` draws the focus rectangle: the canvas has the focus and the focus cues are visible,
+    /// either because the user navigates with the keyboard or because they were forced with `set_show_focus_rect`.
+    pub fn focus_rect_visible(&self) -> bool {
+        use winapi::um::winuser::{WM_QUERYUISTATE, UISF_HIDEFOCUS};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if !unsafe { wh::get_focus(handle) } {
+            return false;
+        }
+
+        match self.show_focus_rect.get() {
+            Some(show) => show,
+            None => wh::send_message(handle, WM_QUERYUISTATE, 0, 0) as u32 & UISF_HIDEFOCUS as u32 == 0
+        }
+    }
+
+    /// Force the focus rectangle to be always shown (`Some(true)`) or never shown (`Some(false)`) when the canvas has the focus.
+    /// With `None`, the focus rectangle follows the keyboard cues of the window, like the native controls.
+    pub fn set_show_focus_rect(&self, show: Option<bool>) {
+        self.show_focus_rect.set(show);
+        self.invalidate();
+    }
+
+    /// Draw the dotted focus rectangle inside the border of the canvas if `focus_rect_visible` is `true`.
+    /// Call this once at the end of the `OnPaint` event, after the content of the canvas was drawn.
+    /// The rectangle is drawn with a XOR operation, so a second call in the same paint erases it.
+    pub fn draw_focus_rect(&self) {
+        use winapi::um::winuser::{GetDC, ReleaseDC, GetClientRect, InflateRect, DrawFocusRect};
+        use winapi::shared::windef::RECT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if !self.focus_rect_visible() {
+            return;
+        }
+
+        unsafe {
+            let mut rect = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+            GetClientRect(handle, &mut rect);
+            InflateRect(&mut rect, -1, -1);
+
+            let dc = GetDC(handle);
+            DrawFocusRect(dc, &rect);
+            ReleaseDC(handle, dc);
+        }
+    }
+
     /// Return the icon of the window
     pub fn icon(&self) -> Option<Icon> {
         use winapi::um::winuser::WM_GETICON;
@@ -0,0 +1,184 @@
+/*!
+    A window created outside of NWG. For example the window of a host application when NWG is used in a plugin.
+
+    An `ExternWindow` can be used as the parent of NWG controls and events handlers can be bound to it.
+    NWG never destroys the window. Dropping the `ExternWindow` only releases the handle.
+
+    Requires the `extern-window` feature.
+
+    ## Example
+    ```rust
+    use native_windows_gui as nwg;
+    use winapi::shared::windef::HWND;
+
+    fn attach(host: HWND, window: &mut nwg::ExternWindow, button: &mut nwg::Button) -> Result<nwg::EventHandler, nwg::NwgError> {
+        nwg::ExternWindow::builder()
+            .hwnd(host)
+            .build(window)?;
+
+        nwg::Button::builder()
+            .text("Plugin button")
+            .parent(&*window)
+            .build(button)?;
+
+        Ok(nwg::bind_event_handler(&button.handle, &window.handle, |_evt, _evt_data, _handle| {}))
+    }
+    ```
+
+    Events handlers bound to an `ExternWindow` subclass the host window. They must be unbound (with `unbind_event_handler`)
+    before the plugin is unloaded. Unbinding a handler removes the subclass, the host window is left as it was.
+*/
+use winapi::shared::windef::HWND;
+use crate::win32::window_helper as wh;
+use crate::win32::base_helper::check_hwnd;
+use crate::NwgError;
+use super::ControlHandle;
+use std::ptr;
+
+const NOT_BOUND: &'static str = "ExternWindow is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: ExternWindow handle is not HWND!";
+
+
+/**
+    A window that was not created by NWG. See the module documentation.
+
+    **Builder parameters:**
+      * `hwnd`: The handle of the existing window. Required.
+
+    **Control events:**
+      * The events of the existing window depend of its class. Generic events (ex: `OnResize`, `OnPaint`, `MousePress(_)`) are raised
+        if an events handler is bound to the window.
+*/
+#[derive(Default, PartialEq, Eq)]
+pub struct ExternWindow {
+    pub handle: ControlHandle
+}
+
+impl ExternWindow {
+
+    pub fn builder() -> ExternWindowBuilder {
+        ExternWindowBuilder {
+            hwnd: ptr::null_mut()
+        }
+    }
+
+    /// Force the window to redraw iteself and all its children
+    pub fn invalidate(&self) {
+        use winapi::um::winuser::InvalidateRect;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
+    }
+
+    /// Return true if the window currently has the keyboard focus
+    pub fn focus(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_focus(handle) }
+    }
+
+    /// Set the keyboard focus on the window
+    pub fn set_focus(&self) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_focus(handle); }
+    }
+
+    /// Return true if the user can interact with the window, return false otherwise
+    pub fn enabled(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_enabled(handle) }
+    }
+
+    /// Enable or disable the window
+    pub fn set_enabled(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_enabled(handle, v) }
+    }
+
+    /// Return true if the window is visible to the user
+    pub fn visible(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_visibility(handle) }
+    }
+
+    /// Show or hide the window
+    pub fn set_visible(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_visibility(handle, v) }
+    }
+
+    /// Return the size of the window
+    pub fn size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_size(handle) }
+    }
+
+    /// Set the size of the window
+    pub fn set_size(&self, x: u32, y: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_size(handle, x, y, true) }
+    }
+
+    /// Return the position of the window
+    pub fn position(&self) -> (i32, i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_position(handle) }
+    }
+
+    /// Set the position of the window
+    pub fn set_position(&self, x: i32, y: i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_position(handle, x, y) }
+    }
+
+    /// Return the window text
+    pub fn text(&self) -> String {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_text(handle) }
+    }
+
+    /// Set the window text
+    pub fn set_text<'a>(&self, v: &'a str) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_text(handle, v) }
+    }
+
+    /// Return the winapi class name of the window
+    pub fn class_name(&self) -> String {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_class_name(handle) }
+    }
+
+}
+
+impl Drop for ExternWindow {
+    /// The window is owned by another application, it must not be destroyed
+    fn drop(&mut self) {
+        self.handle = ControlHandle::NoHandle;
+    }
+}
+
+pub struct ExternWindowBuilder {
+    hwnd: HWND
+}
+
+impl ExternWindowBuilder {
+
+    pub fn hwnd(mut self, hwnd: HWND) -> ExternWindowBuilder {
+        self.hwnd = hwnd;
+        self
+    }
+
+    pub fn build(self, out: &mut ExternWindow) -> Result<(), NwgError> {
+        use winapi::um::winuser::IsWindow;
+
+        if self.hwnd.is_null() || unsafe { IsWindow(self.hwnd) } == 0 {
+            return Err(NwgError::control_create("ExternWindow requires the handle of an existing window"));
+        }
+
+        *out = Default::default();
+        out.handle = ControlHandle::Hwnd(self.hwnd);
+
+        Ok(())
+    }
+
+}
@@ -190,6 +190,12 @@ use super::MessageWindow;
 #[cfg(feature = "message-window")]
 handles!(MessageWindow);
 
+#[cfg(feature = "extern-window")]
+use super::ExternWindow;
+
+#[cfg(feature = "extern-window")]
+handles!(ExternWindow);
+
 #[cfg(feature = "timer")]
 use super::Timer;
 
@@ -221,6 +227,12 @@ use super::Frame;
 #[cfg(feature = "frame")]
 handles!(Frame);
 
+#[cfg(feature = "splitter")]
+use super::Splitter;
+
+#[cfg(feature = "splitter")]
+handles!(Splitter);
+
 
 #[cfg(feature = "rich-textbox")]
 use super::RichTextBox;
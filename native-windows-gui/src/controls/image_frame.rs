@@ -1,5 +1,6 @@
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED};
 use winapi::um::wingdi::DeleteObject;
+use winapi::shared::minwindef::LPARAM;
 use crate::win32::{
     base_helper::check_hwnd,  
     window_helper as wh,
@@ -7,7 +8,7 @@ use crate::win32::{
 };
 use super::{ControlBase, ControlHandle};
 use crate::{Bitmap, Icon, NwgError, RawEventHandler, unbind_raw_event_handler};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 const NOT_BOUND: &'static str = "ImageFrame is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: ImageFrame handle is not HWND!";
@@ -20,6 +21,16 @@ bitflags! {
     }
 }
 
+/// How the image of an image frame is sized in the control
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageFrameScaling {
+    /// The image keeps its size and is centered in the control. The image is clipped if it is bigger than the control.
+    Center,
+
+    /// The image is stretched to the size of the control. The aspect ratio of the image is not kept.
+    Stretch,
+}
+
 /**
 An image frame is a control that displays a `Bitmap` or a `Icon` image resource.
 
@@ -34,6 +45,7 @@ ImageFrame is not behind any features.
   * `background_color`: The background color of the image frame. Used if the image is smaller than the control
   * `bitmap`:           A bitmap to display. If this value is set, icon is ignored.
   * `icon`:             An icon to display
+  * `scaling`:          How the image is sized in the control. Defaults to `ImageFrameScaling::Center`
 
 **Control events:**
   * `OnImageFrameClick`: When the image frame is clicked once by the user
@@ -50,11 +62,15 @@ fn build_frame(button: &mut nwg::ImageFrame, window: &nwg::Window, ico: &nwg::Ic
         .build(button);
 }
 ```
+
+The image frame does not own its image. The `Bitmap` or `Icon` must outlive the time it is displayed.
+Replacing the image (or dropping the image frame) does not destroy the resource that was displayed.
 */
 #[derive(Default)]
 pub struct ImageFrame {
     pub handle: ControlHandle,
     handler0: RefCell<Option<RawEventHandler>>,
+    image_copy: Cell<LPARAM>,
 }
 
 impl ImageFrame {
@@ -68,40 +84,52 @@ impl ImageFrame {
             bitmap: None,
             icon: None,
             parent: None,
-            background_color: None
+            background_color: None,
+            scaling: ImageFrameScaling::Center,
         }
     }
 
     /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
     /// Set `image` to `None` to remove the image
     pub fn set_bitmap<'a>(&self, image: Option<&'a Bitmap>) {
-        use winapi::um::winuser::{STM_SETIMAGE, IMAGE_BITMAP};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        use winapi::um::winuser::{IMAGE_BITMAP, SS_BITMAP};
 
         let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_BITMAP as WPARAM, image_handle);
-        if prev_img != 0 {
-            unsafe { DeleteObject(prev_img as _); }
-        }
+        self.set_image(IMAGE_BITMAP, SS_BITMAP, image_handle);
     }
 
-    /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
+    /// Sets the icon image of the image frame. Replace the current bitmap or icon.
     /// Set `image` to `None` to remove the image
     pub fn set_icon<'a>(&self, image: Option<&'a Icon>) {
-        use winapi::um::winuser::{STM_SETIMAGE, IMAGE_ICON};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        use winapi::um::winuser::{IMAGE_ICON, SS_ICON};
 
         let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
-        if prev_img != 0 {
-            unsafe { DeleteObject(prev_img as _); }
+        self.set_image(IMAGE_ICON, SS_ICON, image_handle);
+    }
+
+    /// Return how the image is sized in the control
+    pub fn scaling(&self) -> ImageFrameScaling {
+        use winapi::um::winuser::SS_REALSIZECONTROL;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match wh::get_style(handle) & SS_REALSIZECONTROL == SS_REALSIZECONTROL {
+            true => ImageFrameScaling::Stretch,
+            false => ImageFrameScaling::Center
         }
     }
 
+    /// Set how the image is sized in the control
+    pub fn set_scaling(&self, scaling: ImageFrameScaling) {
+        use winapi::um::winuser::{SS_CENTERIMAGE, SS_REALSIZECONTROL, InvalidateRect};
+        use std::ptr;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let style = wh::get_style(handle) & !(SS_CENTERIMAGE | SS_REALSIZECONTROL);
+        wh::set_style(handle, style | scaling_flags(scaling));
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
+    }
+
     /// Returns the current image in the image frame.
     /// If the image frame has a bitmap, the value will be returned in `bitmap`
     /// If the image frame has a icon, the value will be returned in `icon`
@@ -186,9 +214,33 @@ impl ImageFrame {
 
     /// Winapi flags required by the control
     pub fn forced_flags(&self) -> u32 {
-        use winapi::um::winuser::{SS_NOTIFY, WS_CHILD, SS_CENTERIMAGE};
+        use winapi::um::winuser::{SS_NOTIFY, WS_CHILD};
 
-        WS_CHILD | SS_NOTIFY | SS_CENTERIMAGE
+        WS_CHILD | SS_NOTIFY
+    }
+
+    /**
+        Display a new image and release the previous one.
+
+        The static control displays a copy of the 32 bits bitmaps with an alpha channel. The copy is owned by the control,
+        so it must be destroyed when it is replaced. The images passed by the user are owned by their resource and are never destroyed.
+    */
+    fn set_image(&self, image_type: u32, style: u32, image_handle: LPARAM) {
+        use winapi::um::winuser::{STM_SETIMAGE, STM_GETIMAGE, SS_TYPEMASK};
+        use winapi::shared::minwindef::WPARAM;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        // The static control only displays the type of image set in its style
+        wh::set_style(handle, (wh::get_style(handle) & !SS_TYPEMASK) | style);
+
+        let prev_img = wh::send_message(handle, STM_SETIMAGE, image_type as WPARAM, image_handle);
+        if prev_img != 0 && prev_img == self.image_copy.get() {
+            unsafe { DeleteObject(prev_img as _); }
+        }
+
+        let current = wh::send_message(handle, STM_GETIMAGE, image_type as WPARAM, 0);
+        self.image_copy.set(if current != image_handle { current } else { 0 });
     }
 
     /// Change the label background color to transparent.
@@ -232,6 +284,12 @@ impl Drop for ImageFrame {
         }
 
         self.handle.destroy();
+
+        // The static control does not destroy its images
+        let copy = self.image_copy.replace(0);
+        if copy != 0 {
+            unsafe { DeleteObject(copy as _); }
+        }
     }
 }
 
@@ -244,6 +302,7 @@ pub struct ImageFrameBuilder<'a> {
     icon: Option<&'a Icon>,
     parent: Option<ControlHandle>,
     background_color: Option<[u8; 3]>,
+    scaling: ImageFrameScaling,
 }
 
 impl<'a> ImageFrameBuilder<'a> {
@@ -288,10 +347,15 @@ impl<'a> ImageFrameBuilder<'a> {
         self
     }
 
+    pub fn scaling(mut self, scaling: ImageFrameScaling) -> ImageFrameBuilder<'a> {
+        self.scaling = scaling;
+        self
+    }
+
     pub fn build(self, out: &mut ImageFrame) -> Result<(), NwgError> {
         use winapi::um::winuser::{SS_BITMAP, SS_ICON};
 
-        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags()) | scaling_flags(self.scaling);
         if self.icon.is_some() {
             flags |= SS_ICON;
         } else {
@@ -330,6 +394,15 @@ impl<'a> ImageFrameBuilder<'a> {
 
 }
 
+fn scaling_flags(scaling: ImageFrameScaling) -> u32 {
+    use winapi::um::winuser::{SS_CENTERIMAGE, SS_REALSIZECONTROL};
+
+    match scaling {
+        ImageFrameScaling::Center => SS_CENTERIMAGE,
+        ImageFrameScaling::Stretch => SS_REALSIZECONTROL,
+    }
+}
+
 impl PartialEq for ImageFrame {
     fn eq(&self, other: &Self) -> bool {
         self.handle == other.handle
@@ -29,6 +29,9 @@ bitflags! {
 /**
 A label is a single line of static text. Use `\r\n` to split the text on multiple lines.
 
+By default, the `&` in the label text are displayed as is. Call `set_mnemonic(true)` (or use `label_for`) to interpret a `&` as the mnemonic
+of the next character, in which case a literal ampersand must be doubled (see `escape_ampersand`).
+
 Label is not behind any features.
 
 **Builder parameters:**
@@ -42,6 +45,9 @@ Label is not behind any features.
   * `font`:             The font used for the label text
   * `background_color`: The background color of the label
   * `h_align`:          The horizontal aligment of the label
+  * `link`:             If the label should behave like a hyperlink. A hand cursor is displayed when the label is hovered.
+  * `link_style`:       If `link` is set, also draw the label text in blue with an underlined font. Defaults to true.
+  * `label_for`:        The control that receives the focus when the mnemonic of the label (ex: `&Name` with ALT+N) is pressed. See `Label::set_label_for`
 
 **Control events:**
   * `OnLabelClick`: When the user click the label
@@ -70,6 +76,8 @@ pub struct Label {
     pub handle: ControlHandle,
     handler0: RefCell<Option<RawEventHandler>>,
     handler1: RefCell<Option<RawEventHandler>>,
+    handler2: RefCell<Option<RawEventHandler>>,
+    link_font: RefCell<Option<Font>>,
 }
 
 impl Label {
@@ -85,7 +93,10 @@ impl Label {
             parent: None,
             h_align: HTextAlign::Left,
             v_align: VTextAlign::Top,
-            background_color: None
+            background_color: None,
+            link: false,
+            link_style: true,
+            label_for: None,
         }
     }
 
@@ -107,6 +118,60 @@ impl Label {
         unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
     }
 
+    /**
+        Associate the label with another control. When the user presses ALT and the character that follows `&` in the label text
+        (ex: ALT+N for `&Name:`), the focus moves to `control`. The `&` is hidden and the character is underlined.
+
+        This works by moving `control` right after the label in the z-order, which is also the tab order. Screen readers use the same
+        order to name text inputs, so the label text is also read for `control`.
+
+        Returns a `NwgError::NotSupported` if `control` is not a window with the same parent as the label.
+        Mnemonics require the keyboard navigation of the window (see `Window::set_dialog_navigation`).
+    */
+    pub fn set_label_for<C: Into<ControlHandle>>(&self, control: C) -> Result<(), NwgError> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let control = match control.into().hwnd() {
+            Some(h) => h,
+            None => { return Err(NwgError::not_supported("Labels can only be associated with window controls")); }
+        };
+
+        if wh::get_window_parent(control) != wh::get_window_parent(handle) {
+            return Err(NwgError::not_supported("The control must have the same parent as the label"));
+        }
+
+        self.set_mnemonic(true);
+
+        unsafe {
+            wh::set_window_after(control, Some(handle));
+        }
+
+        Ok(())
+    }
+
+    /// Return `true` if a `&` in the label text marks a mnemonic, `false` if it is displayed as a literal ampersand
+    pub fn mnemonic(&self) -> bool {
+        use winapi::um::winuser::SS_NOPREFIX;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & SS_NOPREFIX == 0
+    }
+
+    /// Set if a `&` in the label text marks a mnemonic (the `&` is hidden and the next character is underlined)
+    /// or if it is displayed as a literal ampersand. Labels are created without mnemonics unless `label_for` is set.
+    pub fn set_mnemonic(&self, v: bool) {
+        use winapi::um::winuser::{SS_NOPREFIX, InvalidateRect};
+        use std::ptr;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let style = wh::get_style(handle);
+        match v {
+            true => wh::set_style(handle, style & !SS_NOPREFIX),
+            false => wh::set_style(handle, style | SS_NOPREFIX),
+        }
+
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
+    }
+
     /// Return true if the control currently has the keyboard focus
     pub fn focus(&self) -> bool {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -200,14 +265,14 @@ impl Label {
     }
 
     /// Center the text vertically.
-    fn hook_non_client_size(&self, bg: Option<[u8; 3]>, v_align: VTextAlign) {
+    fn hook_non_client_size(&self, bg: Option<[u8; 3]>, text_color: Option<[u8; 3]>, v_align: VTextAlign) {
         use crate::bind_raw_event_handler_inner;
-        use winapi::shared::windef::{HWND, HGDIOBJ, RECT, HBRUSH, POINT};
+        use winapi::shared::windef::{HWND, HDC, HGDIOBJ, RECT, HBRUSH, POINT};
         use winapi::shared::{basetsd::UINT_PTR, minwindef::LRESULT};
         use winapi::um::winuser::{WM_CTLCOLORSTATIC, WM_NCCALCSIZE, WM_NCPAINT, WM_SIZE, DT_CALCRECT, DT_LEFT, NCCALCSIZE_PARAMS, COLOR_WINDOW};
         use winapi::um::winuser::{SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOMOVE, SWP_FRAMECHANGED};
-        use winapi::um::winuser::{GetDC, DrawTextW, ReleaseDC, GetClientRect, GetWindowRect, FillRect, ScreenToClient, SetWindowPos, GetWindowTextW, GetWindowTextLengthW};
-        use winapi::um::wingdi::{SelectObject, CreateSolidBrush, RGB};
+        use winapi::um::winuser::{GetDC, DrawTextW, ReleaseDC, GetClientRect, GetWindowRect, FillRect, ScreenToClient, SetWindowPos, GetWindowTextW, GetWindowTextLengthW, DefWindowProcW};
+        use winapi::um::wingdi::{SelectObject, CreateSolidBrush, SetTextColor, RGB};
         use std::{mem, ptr};
 
         if self.handle.blank() { panic!(NOT_BOUND); }
@@ -222,13 +287,22 @@ impl Label {
 
         unsafe {
 
-        if bg.is_some() {
-            let handler0 = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |_hwnd, msg, _w, l| {
+        if bg.is_some() || text_color.is_some() {
+            let handler0 = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |hwnd, msg, w, l| {
                 match msg {
                     WM_CTLCOLORSTATIC => {
                         let child = l as HWND;
                         if child == handle {
-                            return Some(brush as LRESULT);
+                            let result = match bg.is_some() {
+                                true => brush as LRESULT,
+                                false => DefWindowProcW(hwnd, msg, w, l)
+                            };
+
+                            if let Some(c) = text_color {
+                                SetTextColor(w as HDC, RGB(c[0], c[1], c[2]));
+                            }
+
+                            return Some(result);
                         }
                     },
                     _ => {}
@@ -348,6 +422,56 @@ impl Label {
         }
     }
 
+    /// Display a hand cursor over the label and, if `underline` is set, replace the label font by an underlined copy.
+    fn hook_link(&self, underline: bool) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::shared::windef::HFONT;
+        use winapi::um::winuser::{WM_SETCURSOR, IDC_HAND, LoadCursorW, SetCursor};
+        use winapi::um::wingdi::{GetObjectW, GetStockObject, CreateFontIndirectW, LOGFONTW, DEFAULT_GUI_FONT};
+        use winapi::ctypes::c_int;
+        use std::{mem, ptr};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let handler2 = bind_raw_event_handler_inner(&self.handle, 1, move |_hwnd, msg, _w, _l| {
+            match msg {
+                WM_SETCURSOR => unsafe {
+                    SetCursor(LoadCursorW(ptr::null_mut(), IDC_HAND));
+                    Some(1)
+                },
+                _ => None
+            }
+        });
+
+        *self.handler2.borrow_mut() = Some(handler2.unwrap());
+
+        if !underline {
+            return;
+        }
+
+        unsafe {
+            let mut font_handle = wh::get_window_font(handle);
+            if font_handle.is_null() {
+                font_handle = GetStockObject(DEFAULT_GUI_FONT as c_int) as HFONT;
+            }
+
+            let mut info: LOGFONTW = mem::zeroed();
+            let size = mem::size_of::<LOGFONTW>() as c_int;
+            if GetObjectW(font_handle as _, size, &mut info as *mut LOGFONTW as _) != size {
+                return;
+            }
+
+            info.lfUnderline = 1;
+            let link_font = CreateFontIndirectW(&info);
+            if link_font.is_null() {
+                return;
+            }
+
+            wh::set_window_font(handle, Some(link_font), true);
+            *self.link_font.borrow_mut() = Some(Font { handle: link_font });
+        }
+    }
+
 }
 
 impl PartialEq for Label {
@@ -369,7 +493,17 @@ impl Drop for Label {
             drop(unbind_raw_event_handler(h));
         }
 
+        let handler = self.handler2.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
+
+        // The underlined font is owned by the label
+        if let Some(font) = self.link_font.borrow_mut().take() {
+            unsafe { winapi::um::wingdi::DeleteObject(font.handle as _); }
+        }
     }
 }
 
@@ -383,6 +517,9 @@ pub struct LabelBuilder<'a> {
     font: Option<&'a Font>,
     h_align: HTextAlign,
     v_align: VTextAlign,
+    link: bool,
+    link_style: bool,
+    label_for: Option<ControlHandle>,
     parent: Option<ControlHandle>
 }
 
@@ -433,6 +570,21 @@ impl<'a> LabelBuilder<'a> {
         self
     }
 
+    pub fn link(mut self, link: bool) -> LabelBuilder<'a> {
+        self.link = link;
+        self
+    }
+
+    pub fn link_style(mut self, style: bool) -> LabelBuilder<'a> {
+        self.link_style = style;
+        self
+    }
+
+    pub fn label_for<C: Into<ControlHandle>>(mut self, control: Option<C>) -> LabelBuilder<'a> {
+        self.label_for = control.map(|c| c.into());
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> LabelBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -474,7 +626,21 @@ impl<'a> LabelBuilder<'a> {
             out.set_font(Font::global_default().as_ref());
         }
 
-        out.hook_non_client_size(self.background_color, self.v_align);
+        let link_style = self.link && self.link_style;
+        let text_color = match link_style {
+            true => Some([0, 102, 204]),
+            false => None
+        };
+
+        out.hook_non_client_size(self.background_color, text_color, self.v_align);
+
+        if self.link {
+            out.hook_link(link_style);
+        }
+
+        if let Some(control) = self.label_for {
+            out.set_label_for(control)?;
+        }
 
         Ok(())
     }
@@ -1,11 +1,12 @@
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::{WPARAM, LPARAM};
-use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_NOSEL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
+use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_EXTENDEDSEL, LBS_NOSEL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{to_utf16, from_utf16, check_hwnd};
-use crate::{Font, NwgError};
-use super::{ControlBase, ControlHandle};
-use std::cell::{Ref, RefMut, RefCell};
+use crate::win32::base_helper::check_hwnd;
+use crate::{Font, NwgError, RawEventHandler};
+use super::{ControlBase, ControlHandle, WideBuffer};
+use std::cell::{Ref, RefMut, RefCell, Cell};
+use std::rc::Rc;
 use std::fmt::Display;
 use std::ops::Range;
 use std::mem;
@@ -21,7 +22,9 @@ bitflags! {
         * NONE:     No flags. Equivalent to a invisible listbox.
         * VISIBLE:  The listbox is immediatly visible after creation
         * DISABLED: The listbox cannot be interacted with by the user. It also has a grayed out look.
-        * MULTI_SELECT: It is possible for the user to select more than 1 item at a time
+        * MULTI_SELECT: It is possible for the user to select more than 1 item at a time. Each click toggles the selection of an item.
+        * EXTENDED_SELECT: Like `MULTI_SELECT`, but with the usual selection keys: a click selects one item, CTRL+click toggles an item,
+          and SHIFT+click or SHIFT+arrows select a range of items.
         * NO_SELECT: It is impossible for the user to select the listbox items
         * TAB_STOP: The control can be selected using tab navigation
     */
@@ -30,6 +33,7 @@ bitflags! {
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const MULTI_SELECT = LBS_MULTIPLESEL;
+        const EXTENDED_SELECT = LBS_EXTENDEDSEL;
         const NO_SELECT = LBS_NOSEL;
         const TAB_STOP = WS_TABSTOP;
     }
@@ -56,6 +60,7 @@ Requires the `list-box` feature.
 **Control events:**
   * `OnListBoxSelect`: When the current listbox selection is changed
   * `OnListBoxDoubleClick`: When a listbox item is clicked twice rapidly
+  * `OnListBoxItemMoved`: When the user moved an item by dragging it. See `set_drag_reorder`
   * `MousePress(_)`: Generic mouse press events on the listbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -77,7 +82,9 @@ fn build_listbox(listb: &mut nwg::ListBox<&'static str>, window: &nwg::Window, f
 #[derive(Default)]
 pub struct ListBox<D: Display+Default> {
     pub handle: ControlHandle,
-    collection: RefCell<Vec<D>>
+    handler0: RefCell<Option<RawEventHandler>>,
+    collection: Rc<RefCell<Vec<D>>>,
+    drag_reorder: Rc<Cell<bool>>,
 }
 
 impl<D: Display+Default> ListBox<D> {
@@ -104,11 +111,9 @@ impl<D: Display+Default> ListBox<D> {
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+        let mut display_os = WideBuffer::from_str(&display);
 
-        unsafe {
-            wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
-        }
+        wh::send_message(handle, LB_ADDSTRING, 0, display_os.as_lparam());
 
         self.collection.borrow_mut().push(item);
     }
@@ -122,7 +127,7 @@ impl<D: Display+Default> ListBox<D> {
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+        let mut display_os = WideBuffer::from_str(&display);
 
         let mut col = self.collection.borrow_mut();
         if index == std::usize::MAX {
@@ -131,9 +136,7 @@ impl<D: Display+Default> ListBox<D> {
             col.insert(index, item);
         }
 
-        unsafe {
-            wh::send_message(handle, LB_INSERTSTRING, index, mem::transmute(display_os.as_ptr()));
-        }
+        wh::send_message(handle, LB_INSERTSTRING, index, display_os.as_lparam());
     }
 
 
@@ -149,6 +152,18 @@ impl<D: Display+Default> ListBox<D> {
         col_ref.remove(index)
     }
 
+    /// Move the item at `from` to the index `to`, in the control and in the collection. The item data is moved with the item.
+    /// Panic if one of the indices is out of bounds
+    pub fn move_item(&self, from: usize, to: usize) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut col = self.collection.borrow_mut();
+        let item = col.remove(from);
+        col.insert(to, item);
+
+        move_item_inner(handle, from, to, false);
+    }
+
     /// Return the index of the currencty selected item for single value list box.
     /// Return `None` if no item is selected.
     pub fn selection(&self) -> Option<usize> {
@@ -184,7 +199,7 @@ impl<D: Display+Default> ListBox<D> {
             value => value as usize
         };
 
-        if select_count == usize::max_value() || usize::max_value() == 0 {
+        if select_count == usize::max_value() || select_count == 0 {
             return Vec::new();
         }
 
@@ -205,7 +220,6 @@ impl<D: Display+Default> ListBox<D> {
     /// Return `None` if no item is selected. This reads the visual value.
     pub fn selection_string(&self) -> Option<String> {
         use winapi::um::winuser::{LB_GETCURSEL, LB_GETTEXTLEN, LB_GETTEXT, LB_ERR};
-        use winapi::shared::ntdef::WCHAR;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         let index = wh::send_message(handle, LB_GETCURSEL, 0, 0);
@@ -214,16 +228,28 @@ impl<D: Display+Default> ListBox<D> {
         else {
             let index = index as usize;
             let length = (wh::send_message(handle, LB_GETTEXTLEN, index, 0) as usize) + 1;  // +1 for the terminating null character
-            let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
-            unsafe { 
-                buffer.set_len(length); 
-                wh::send_message(handle, LB_GETTEXT, index, mem::transmute(buffer.as_ptr()));
-            }
+            let mut buffer = WideBuffer::new(length);
+            wh::send_message(handle, LB_GETTEXT, index, buffer.as_lparam());
 
-            Some(from_utf16(&buffer))
+            Some(buffer.to_string())
         }
     }
 
+    /// Return the display value of the selected items of a multi select list box, in the list order.
+    /// Returns an empty vector if no items are selected or for single select list box. This reads the visual values.
+    pub fn multi_selection_strings(&self) -> Vec<String> {
+        use winapi::um::winuser::{LB_GETTEXTLEN, LB_GETTEXT};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        self.multi_selection().into_iter().map(|index| {
+            let length = (wh::send_message(handle, LB_GETTEXTLEN, index, 0) as usize) + 1;  // +1 for the terminating null character
+            let mut buffer = WideBuffer::new(length);
+            wh::send_message(handle, LB_GETTEXT, index, buffer.as_lparam());
+            buffer.to_string()
+        }).collect()
+    }
+
     /// Set the currently selected item in the list box for single value list box.
     /// Does nothing if the index is out of bound
     /// If the value is None, remove the selected value
@@ -290,15 +316,13 @@ impl<D: Display+Default> ListBox<D> {
         use winapi::um::winuser::{LB_SELECTSTRING, LB_ERR};
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let os_string = to_utf16(value);
+        let mut os_string = WideBuffer::from_str(value);
 
-        unsafe {
-            let index = wh::send_message(handle, LB_SELECTSTRING, 0, mem::transmute(os_string.as_ptr()));
-            if index == LB_ERR {
-                None
-            } else {
-                Some(index as usize)
-            }
+        let index = wh::send_message(handle, LB_SELECTSTRING, 0, os_string.as_lparam());
+        if index == LB_ERR {
+            None
+        } else {
+            Some(index as usize)
         }
     }
 
@@ -311,44 +335,99 @@ impl<D: Display+Default> ListBox<D> {
         wh::send_message(handle, LB_GETSEL, index as WPARAM, 0) > 0
     }
 
+    /// Search an item that begins by the value (or that is equal to the value if `exact` is true) without changing the selection.
+    /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
+    /// Return the index of the first item found or None if the search was not successful
+    pub fn find_item(&self, value: &str, exact: bool) -> Option<usize> {
+        use winapi::um::winuser::{LB_FINDSTRING, LB_FINDSTRINGEXACT, LB_ERR};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut os_string = WideBuffer::from_str(value);
+
+        let msg = match exact {
+            true => LB_FINDSTRINGEXACT,
+            false => LB_FINDSTRING
+        };
+
+        // Start index of -1 searches the whole list from the beginning
+        let index = wh::send_message(handle, msg, usize::max_value(), os_string.as_lparam());
+        if index == LB_ERR {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
+
+    /**
+        Return the value attached to the item at `index` with `set_item_data`. Return None if the index is out of range.
+        Items that never had a value attached return 0.
+    */
+    pub fn item_data(&self, index: usize) -> Option<usize> {
+        use winapi::um::winuser::LB_GETITEMDATA;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match index < self.len() {
+            true => Some(wh::send_message(handle, LB_GETITEMDATA, index as WPARAM, 0) as usize),
+            false => None
+        }
+    }
+
+    /**
+        Attach a pointer sized value to the item at `index`. Ex: the database key of the item.
+        Does nothing if the index is out of range.
+
+        The value is stored by the system control, not in the rust collection. It is lost when the item is removed
+        or when the items are rebuilt from the collection (ex: `sync`, `set_collection`). If the value is a pointer,
+        the control does not free it.
+    */
+    pub fn set_item_data(&self, index: usize, data: usize) {
+        use winapi::um::winuser::LB_SETITEMDATA;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if index < self.len() {
+            wh::send_message(handle, LB_SETITEMDATA, index as WPARAM, data as LPARAM);
+        }
+    }
+
     /// Update the visual of the control with the inner collection.
     /// This rebuild every item in the list box and can take some time on big collections.
+    /// The control is only repainted once all the items are added.
     pub fn sync(&self) {
         use winapi::um::winuser::{LB_ADDSTRING, LB_INITSTORAGE};
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
-        self.clear_inner(handle);
+        wh::without_redraw(handle, || {
+            self.clear_inner(handle);
 
-        let item_count = self.collection.borrow().len();
-        wh::send_message(handle, LB_INITSTORAGE, item_count as WPARAM, (10*item_count) as LPARAM);
+            let item_count = self.collection.borrow().len();
+            wh::send_message(handle, LB_INITSTORAGE, item_count as WPARAM, (10*item_count) as LPARAM);
 
-        for item in self.collection.borrow().iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            
-            unsafe {
-                wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
+            for item in self.collection.borrow().iter() {
+                let display = format!("{}", item);
+                let mut display_os = WideBuffer::from_str(&display);
+                wh::send_message(handle, LB_ADDSTRING, 0, display_os.as_lparam());
             }
-        }
+        });
     }
 
     /// Set the item collection of the list box. Return the old collection
+    /// The control is only repainted once all the items are added.
+    /// To add many items with `push` or `insert`, wrap the calls in `handle.set_redraw(false)` and `handle.set_redraw(true)`.
     pub fn set_collection(&self, mut col: Vec<D>) -> Vec<D> {
         use winapi::um::winuser::LB_ADDSTRING;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
-        self.clear_inner(handle);
+        wh::without_redraw(handle, || {
+            self.clear_inner(handle);
 
-        for item in col.iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            
-            unsafe {
-                wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
+            for item in col.iter() {
+                let display = format!("{}", item);
+                let mut display_os = WideBuffer::from_str(&display);
+                wh::send_message(handle, LB_ADDSTRING, 0, display_os.as_lparam());
             }
-        }
+        });
 
         let mut col_ref = self.collection.borrow_mut();
         mem::swap::<Vec<D>>(&mut col_ref, &mut col);
@@ -491,12 +570,207 @@ impl<D: Display+Default> ListBox<D> {
 
 }
 
+impl<D: Display+Default+'static> ListBox<D> {
+
+    /// Return `true` if the user can reorder the items by dragging them
+    pub fn drag_reorder(&self) -> bool {
+        self.drag_reorder.get()
+    }
+
+    /**
+        Allow the user to reorder the items by dragging them with the mouse. A line is drawn where the item will be inserted.
+        Once the mouse is released, the item and its item data are moved in the control and in the inner collection,
+        the moved item is selected, and `OnListBoxItemMoved` is raised.
+
+        The item type must be `'static` because the collection is updated from the control event handler.
+    */
+    pub fn set_drag_reorder(&self, enabled: bool) {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        self.drag_reorder.set(enabled);
+        if enabled && self.handler0.borrow().is_none() {
+            self.hook_drag_reorder();
+        }
+    }
+
+    /// Move the items when they are dragged by the user
+    fn hook_drag_reorder(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::NWG_ITEM_MOVED;
+        use winapi::um::winuser::{WM_LBUTTONDOWN, WM_MOUSEMOVE, WM_LBUTTONUP, WM_CAPTURECHANGED, ReleaseCapture, PostMessageW};
+        use winapi::shared::minwindef::HIWORD;
+
+        let enabled = self.drag_reorder.clone();
+        let collection = self.collection.clone();
+
+        // The index of the dragged item and the position of the insertion line currently drawn, if any
+        let drag: Rc<Cell<Option<(usize, Option<i32>)>>> = Rc::new(Cell::new(None));
+
+        let handler = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, _w, l| {
+            match msg {
+                WM_LBUTTONDOWN => {
+                    if enabled.get() {
+                        drag.set(item_from_point(hwnd, l).map(|index| (index, None)));
+                    }
+                    None
+                },
+                WM_MOUSEMOVE => {
+                    let (from, line) = drag.get()?;
+
+                    // Dropping the item right before or right after itself does nothing
+                    let (pos, y) = insertion_point(hwnd, HIWORD(l as u32) as i16 as i32);
+                    let new_line = match pos == from || pos == from + 1 {
+                        true => None,
+                        false => Some(y)
+                    };
+
+                    if new_line != line {
+                        if let Some(y) = line { draw_insertion_line(hwnd, y); }
+                        if let Some(y) = new_line { draw_insertion_line(hwnd, y); }
+                        drag.set(Some((from, new_line)));
+                    }
+
+                    // The list box must not update the selection while an item is dragged
+                    Some(0)
+                },
+                WM_LBUTTONUP => {
+                    let (from, line) = drag.take()?;
+                    let y = line?;
+                    draw_insertion_line(hwnd, y);
+
+                    let (pos, _) = insertion_point(hwnd, HIWORD(l as u32) as i16 as i32);
+                    let to = if pos > from { pos - 1 } else { pos };
+
+                    {
+                        let mut col = collection.borrow_mut();
+                        if from < col.len() && to < col.len() {
+                            let item = col.remove(from);
+                            col.insert(to, item);
+                        }
+                    }
+
+                    move_item_inner(hwnd, from, to, true);
+
+                    unsafe {
+                        PostMessageW(hwnd, NWG_ITEM_MOVED, from as WPARAM, to as LPARAM);
+                        ReleaseCapture();
+                    }
+
+                    Some(0)
+                },
+                WM_CAPTURECHANGED => {
+                    // The drag was cancelled
+                    if let Some((_, Some(y))) = drag.take() {
+                        draw_insertion_line(hwnd, y);
+                    }
+                    None
+                },
+                _ => None
+            }
+        });
+
+        *self.handler0.borrow_mut() = Some(handler.unwrap());
+    }
+
+}
+
 impl<D: Display+Default> Drop for ListBox<D> {
     fn drop(&mut self) {
+        use crate::unbind_raw_event_handler;
+
+        let handler = self.handler0.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
     }
 }
 
+/// Move an item and its item data in the list box control. The rust collection is not touched.
+/// The moved item keeps its selected state, or it is selected if `select` is true.
+fn move_item_inner(handle: HWND, from: usize, to: usize, select: bool) {
+    use winapi::um::winuser::{LB_GETTEXTLEN, LB_GETTEXT, LB_GETITEMDATA, LB_GETSEL, LB_DELETESTRING, LB_INSERTSTRING,
+        LB_SETITEMDATA, LB_SETSEL, LB_SETCURSEL, LB_ERR, LBS_MULTIPLESEL, LBS_EXTENDEDSEL};
+
+    let length = wh::send_message(handle, LB_GETTEXTLEN, from as WPARAM, 0);
+    if length == LB_ERR {
+        panic!("ListBox index {} is out of bounds", from);
+    }
+
+    let mut buffer = WideBuffer::new(length as usize + 1);
+    let data = wh::send_message(handle, LB_GETITEMDATA, from as WPARAM, 0);
+    let selected = select || wh::send_message(handle, LB_GETSEL, from as WPARAM, 0) > 0;
+
+    wh::send_message(handle, LB_GETTEXT, from as WPARAM, buffer.as_lparam());
+
+    wh::without_redraw(handle, || {
+        wh::send_message(handle, LB_DELETESTRING, from as WPARAM, 0);
+        wh::send_message(handle, LB_INSERTSTRING, to as WPARAM, buffer.as_lparam());
+        wh::send_message(handle, LB_SETITEMDATA, to as WPARAM, data as LPARAM);
+    });
+
+    let multi = wh::get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0;
+    match (multi, selected) {
+        (true, _) => { wh::send_message(handle, LB_SETSEL, selected as WPARAM, to as LPARAM); },
+        (false, true) => { wh::send_message(handle, LB_SETCURSEL, to as WPARAM, 0); },
+        (false, false) => {}
+    }
+}
+
+/// Return the index of the item under a point (in the `lparam` format). `None` if there is no item at this point.
+fn item_from_point(handle: HWND, point: LPARAM) -> Option<usize> {
+    use winapi::um::winuser::LB_ITEMFROMPOINT;
+    use winapi::shared::minwindef::{HIWORD, LOWORD};
+
+    let result = wh::send_message(handle, LB_ITEMFROMPOINT, 0, point) as u32;
+    match HIWORD(result) {
+        0 => Some(LOWORD(result) as usize),
+        _ => None
+    }
+}
+
+/// Return the index where a dragged item would be inserted at the client coordinate `y`,
+/// and the coordinate of the line between the items.
+fn insertion_point(handle: HWND, y: i32) -> (usize, i32) {
+    use winapi::um::winuser::{LB_GETCOUNT, LB_GETITEMRECT, LB_ITEMFROMPOINT};
+    use winapi::shared::minwindef::{LOWORD, MAKELONG};
+    use winapi::shared::windef::RECT;
+
+    let count = wh::send_message(handle, LB_GETCOUNT, 0, 0);
+    if count <= 0 {
+        return (0, 0);
+    }
+
+    // LB_ITEMFROMPOINT returns the nearest item if the point is outside the items
+    let point = MAKELONG(0, y.max(0) as u16) as LPARAM;
+    let index = LOWORD(wh::send_message(handle, LB_ITEMFROMPOINT, 0, point) as u32) as usize;
+
+    let mut rect: RECT = unsafe { mem::zeroed() };
+    unsafe { wh::send_message(handle, LB_GETITEMRECT, index as WPARAM, mem::transmute(&mut rect)); }
+
+    match y > (rect.top + rect.bottom) / 2 {
+        true => (index + 1, rect.bottom),
+        false => (index, rect.top)
+    }
+}
+
+/// Invert a thin line at the client coordinate `y`. Drawing the line twice removes it.
+fn draw_insertion_line(handle: HWND, y: i32) {
+    use winapi::um::winuser::{GetDC, ReleaseDC, GetClientRect};
+    use winapi::um::wingdi::{PatBlt, DSTINVERT};
+    use winapi::shared::windef::RECT;
+
+    unsafe {
+        let mut rect: RECT = mem::zeroed();
+        GetClientRect(handle, &mut rect);
+
+        let dc = GetDC(handle);
+        PatBlt(dc, 0, y - 1, rect.right, 2, DSTINVERT);
+        ReleaseDC(handle, dc);
+    }
+}
+
 pub struct ListBoxBuilder<'a, D: Display+Default> {
     size: (i32, i32),
     position: (i32, i32),
@@ -598,7 +872,7 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
             out.set_collection(col);
         }
 
-        if flags & LBS_MULTIPLESEL == LBS_MULTIPLESEL {
+        if flags & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0 {
             for i in self.multi_selection {
                 out.multi_add_selection(i);
             }
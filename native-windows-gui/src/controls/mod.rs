@@ -71,23 +71,30 @@ mod number_select;
 #[cfg(feature = "extern-canvas")]
 mod extern_canvas;
 
+#[cfg(feature = "extern-window")]
+mod extern_window;
+
 #[cfg(feature = "frame")]
 mod frame;
 
+#[cfg(feature = "splitter")]
+mod splitter;
+
 #[cfg(feature = "scroll-bar")]
 mod scroll_bar;
 
 mod handle_from_control;
 
-pub use control_handle::ControlHandle;
+pub use control_handle::{ControlHandle, ControlCapture};
 pub use control_base::{ControlBase, HwndBuilder, TimerBuilder as BaseTimerBuilder, OtherBuilder};
-pub use window::{Window, WindowBuilder, WindowFlags};
+pub(crate) use control_base::WideBuffer;
+pub use window::{Window, WindowBuilder, WindowFlags, WindowFrame};
 pub use button::{Button, ButtonBuilder, ButtonFlags};
 pub use check_box::{CheckBox, CheckBoxBuilder, CheckBoxState, CheckBoxFlags};
 pub use radio_button::{RadioButton, RadioButtonBuilder, RadioButtonState, RadioButtonFlags};
-pub use text_input::{TextInput, TextInputBuilder, TextInputFlags};
+pub use text_input::{TextInput, TextInputBuilder, TextInputFlags, InputMask};
 pub use label::{Label, LabelBuilder, LabelFlags};
-pub use image_frame::{ImageFrame, ImageFrameBuilder, ImageFrameFlags};
+pub use image_frame::{ImageFrame, ImageFrameBuilder, ImageFrameFlags, ImageFrameScaling};
 
 #[cfg(feature = "textbox")]
 pub use text_box::{TextBox, TextBoxBuilder, TextBoxFlags};
@@ -158,9 +165,15 @@ pub use number_select::{NumberSelect, NumberSelectBuilder, NumberSelectFlags, Nu
 #[cfg(feature = "extern-canvas")]
 pub use extern_canvas::{ExternCanvas, ExternCanvasBuilder, ExternCanvasFlags};
 
+#[cfg(feature = "extern-window")]
+pub use extern_window::{ExternWindow, ExternWindowBuilder};
+
 #[cfg(feature = "frame")]
 pub use frame::{Frame, FrameBuilder, FrameFlags};
 
+#[cfg(feature = "splitter")]
+pub use splitter::{Splitter, SplitterBuilder, SplitterFlags, SplitterOrientation};
+
 #[cfg(feature = "scroll-bar")]
 pub use scroll_bar::{ScrollBar, ScrollBarBuilder, ScrollBarFlags};
 
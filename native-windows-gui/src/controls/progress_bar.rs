@@ -41,7 +41,7 @@ Requires the `progress-bar` feature.
   * `parent`:         **Required.** The progress bar parent container.
   * `size`:           The progress bar size.
   * `position`:       The progress bar position.
-  * `state`:          The initial state of the progress bar.
+  * `state`:          The initial state of the progress bar. Must be `Normal` with the `MARQUEE` flag.
   * `step`:           The value in which the progress bar value increase when `advance` is used.
   * `pos`:            The initial value of the progress bar.
   * `range`:          The minimum and maximum value in the progress bar.
@@ -92,7 +92,8 @@ impl ProgressBar {
         }
     }
 
-    /// Return the current state of the progress bar
+    /// Return the current state of the progress bar.
+    /// Returns `Normal` if visual styles are not enabled because the states are not supported in that case.
     pub fn state(&self) -> ProgressBarState {
         use winapi::um::commctrl::{PBM_GETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
         
@@ -102,16 +103,25 @@ impl ProgressBar {
             PBST_NORMAL => ProgressBarState::Normal,
             PBST_ERROR => ProgressBarState::Error,
             PBST_PAUSED => ProgressBarState::Paused,
-            _ => panic!("Unkown progress bar state")
+            _ => ProgressBarState::Normal
         }
     }
 
-    /// Set the state of the progress bar
-    pub fn set_state(&self, state: ProgressBarState) {
+    /**
+        Set the state of the progress bar. Ex: use `Error` to paint the bar in red when a download fails.
+
+        States require visual styles (comctl32 v6, enabled by the application manifest). Without them, this does nothing.
+        States only change the color of regular bars. Windows ignores them on a bar with the `MARQUEE` flag,
+        so this returns a `NwgError::NotSupported` for such a bar.
+    */
+    pub fn set_state(&self, state: ProgressBarState) -> Result<(), NwgError> {
         use winapi::um::commctrl::{PBM_SETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
         use winapi::shared::minwindef::WPARAM;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if wh::get_style(handle) & PBS_MARQUEE == PBS_MARQUEE {
+            return Err(NwgError::not_supported("ProgressBar states are not supported with the MARQUEE flag"));
+        }
 
         let state = match state {
             ProgressBarState::Normal => PBST_NORMAL,
@@ -120,6 +130,8 @@ impl ProgressBar {
         };
 
         wh::send_message(handle, PBM_SETSTATE, state as WPARAM, 0);
+
+        Ok(())
     }
 
     /// Increase the bar value by the step value
@@ -401,7 +413,10 @@ impl ProgressBarBuilder {
             .parent(Some(parent))
             .build()?;
 
-        out.set_state(self.state);
+        if self.state != ProgressBarState::Normal {
+            out.set_state(self.state)?;
+        }
+
         out.set_step(self.step);
         out.set_pos(self.pos);
         out.set_range(self.range);
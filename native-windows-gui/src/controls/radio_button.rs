@@ -15,7 +15,7 @@ bitflags! {
         * VISIBLE:  The radio button is immediatly visible after creation
         * DISABLED: The radio button cannot be interacted with by the user. It also has a grayed out look.
         * TAB_STOP: The radio button can be selected using tab navigation
-        * GROUP:    Creates a new radio button group
+        * GROUP:    Creates a new radio button group. The first radio button of a group is also a tab stop.
     */
     pub struct RadioButtonFlags: u32 {
         const VISIBLE = WS_VISIBLE;
@@ -40,9 +40,14 @@ Radiobutton is not behind any features.
 
 Note: Internally, radio buttons are `Button` and as such, they trigger the same events
 
+**Keyboard navigation:** A group starts at a radio button with the `GROUP` flag and ends before the next control with the `GROUP` flag.
+TAB moves the focus to the group, and the arrow keys move the focus and the check state to the previous or the next radio button
+of the group, wrapping around and skipping the disabled radio buttons. The newly checked radio button raises `OnButtonClick`.
+This requires the dialog navigation of the parent window (see `Window::set_dialog_navigation`).
+
 **Builder parameters:**
   * `parent`:           **Required.** The radio button parent container.
-  * `text`:             The radio button text.
+  * `text`:             The radio button text. A `&` marks a mnemonic, use `&&` (or `escape_ampersand`) to display a literal ampersand.
   * `size`:             The radio button size.
   * `position`:         The radio button position.
   * `enabled`:          If the radio button can be used by the user. It also has a grayed out look if disabled.
@@ -53,7 +58,7 @@ Note: Internally, radio buttons are `Button` and as such, they trigger the same
   * `check_state`:      The default check state
 
 **Control events:**
-  * `OnButtonClick`: When the adio button is clicked once by the user
+  * `OnButtonClick`: When the radio button is clicked once by the user. `EventData::on_button_click` returns the new check state
   * `OnButtonDoubleClick`: When the adio button is clicked twice rapidly by the user
   * `MousePress(_)`: Generic mouse press events on the adio button
   * `OnMouseMove`: Generic mouse mouse event
@@ -372,7 +377,12 @@ impl<'a> RadioButtonBuilder<'a> {
     }
 
     pub fn build(self, out: &mut RadioButton) -> Result<(), NwgError> {
-        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        // Without a tab stop, the group cannot be reached with the keyboard
+        if flags & WS_GROUP == WS_GROUP {
+            flags |= WS_TABSTOP;
+        }
 
         let parent = match self.parent {
             Some(p) => Ok(p),
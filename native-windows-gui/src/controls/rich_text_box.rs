@@ -255,6 +255,7 @@ Note: Use `\r\n` to input a new line not just `\n`.
   * `OnKeyPress`:    Generic key press event
   * `OnKeyRelease`:  Generic key release event
   * `OnChar`:        Generic key event. Returns a `char` instead of a virtual key code
+  * `OnRichTextBoxSelectionChanged`: When the selected text or the caret position changes
 */
 #[derive(Default, PartialEq, Eq)]
 pub struct RichTextBox {
@@ -294,6 +295,27 @@ impl RichTextBox {
         rich::set_char_format(handle, fmt);
     }
 
+    /**
+        Add `text` at the end of the control using the character format `fmt`. Ex: to add a colored line in a log.
+        The previous selection is restored, so the user can keep selecting text while new text is appended.
+    */
+    pub fn append_text(&self, text: &str, fmt: &CharFormat) {
+        use winapi::um::winuser::{EM_SETSEL, EM_REPLACESEL, GetWindowTextLengthW};
+        use crate::win32::base_helper::to_utf16;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let selection = self.selection();
+        let text = to_utf16(text);
+
+        // Move the caret after the last character. A start of -1 would only remove the selection.
+        let len = unsafe { GetWindowTextLengthW(handle) };
+        wh::send_message(handle, EM_SETSEL as u32, len as WPARAM, len as LPARAM);
+        rich::set_char_format(handle, fmt);
+        wh::send_message(handle, EM_REPLACESEL as u32, 0, text.as_ptr() as LPARAM);
+
+        self.set_selection(selection);
+    }
+
     /// Returns the character format of the current selected text
     pub fn char_format(&self) -> CharFormat {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -594,6 +616,11 @@ impl<'a> RichTextBoxBuilder<'a> {
             .parent(Some(parent))
             .build()?;
 
+        // Rich edit controls only send the notifications listed in their event mask
+        let handle = out.handle.hwnd().unwrap();
+        let mask = wh::send_message(handle, rich::EM_GETEVENTMASK, 0, 0) as u32;
+        wh::send_message(handle, rich::EM_SETEVENTMASK, 0, (mask | rich::ENM_SELCHANGE) as LPARAM);
+
         if self.limit > 0 {
             out.set_limit(self.limit);
         }
@@ -0,0 +1,399 @@
+use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_CHILD};
+use crate::win32::window_helper as wh;
+use crate::win32::base_helper::check_hwnd;
+use crate::{NwgError, RawEventHandler, unbind_raw_event_handler};
+use super::{ControlBase, ControlHandle};
+use std::cell::RefCell;
+
+const NOT_BOUND: &'static str = "Splitter is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: Splitter handle is not HWND!";
+
+
+bitflags! {
+    /**
+        The splitter flags
+
+        * NONE:     No flags. Equivalent to a invisible splitter.
+        * VISIBLE:  The splitter is immediatly visible after creation
+        * DISABLED: The splitter cannot be dragged by the user.
+    */
+    pub struct SplitterFlags: u32 {
+        const NONE = 0;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+    }
+}
+
+/// The direction of a splitter
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SplitterOrientation {
+    /// A vertical bar between a left pane (`first`) and a right pane (`second`). Dragging it moves it horizontally.
+    Vertical,
+
+    /// A horizontal bar between a top pane (`first`) and a bottom pane (`second`). Dragging it moves it vertically.
+    Horizontal,
+}
+
+/**
+A splitter is a bar between two sibling controls (the panes) that the user can drag to share the space between them.
+Ex: a resizable sidebar next to the main view of a window.
+
+The splitter does not place the panes. Build the panes and the splitter next to each other in the same parent,
+the splitter only moves its inner edges when it is dragged. The outer edges of the panes are kept in place.
+Each pane also receives the `OnResize` event while the splitter is dragged.
+
+Requires the `splitter` feature.
+
+**Builder parameters:**
+  * `parent`:          **Required.** The splitter parent container. Must be the parent of the panes.
+  * `first`:           **Required.** The left pane of a vertical splitter or the top pane of a horizontal splitter.
+  * `second`:          **Required.** The right pane of a vertical splitter or the bottom pane of a horizontal splitter.
+  * `orientation`:     The direction of the splitter. Defaults to `SplitterOrientation::Vertical`.
+  * `size`:            The splitter size.
+  * `position`:        The splitter position.
+  * `first_min_size`:  The minimum width (vertical splitter) or height (horizontal splitter) of the first pane.
+  * `second_min_size`: The minimum width (vertical splitter) or height (horizontal splitter) of the second pane.
+  * `enabled`:         If the splitter can be dragged by the user. A disabled splitter does not resize the panes.
+  * `flags`:           A combination of the SplitterFlags values.
+  * `ex_flags`:        A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
+
+**Control events:**
+  * `MousePress(_)`: Generic mouse press events on the splitter
+  * `OnMouseMove`: Generic mouse mouse event
+
+```rust
+use native_windows_gui as nwg;
+fn build_splitter(splitter: &mut nwg::Splitter, window: &nwg::Window, sidebar: &nwg::Frame, view: &nwg::Frame) {
+    nwg::Splitter::builder()
+        .position((200, 0))
+        .size((5, 500))
+        .first(sidebar)
+        .second(view)
+        .first_min_size(100)
+        .parent(window)
+        .build(splitter);
+}
+```
+*/
+#[derive(Default)]
+pub struct Splitter {
+    pub handle: ControlHandle,
+    orientation: Option<SplitterOrientation>,
+    handler0: RefCell<Option<RawEventHandler>>,
+}
+
+impl Splitter {
+
+    pub fn builder() -> SplitterBuilder {
+        SplitterBuilder {
+            size: (5, 100),
+            position: (0, 0),
+            orientation: SplitterOrientation::Vertical,
+            first: None,
+            second: None,
+            first_min_size: 0,
+            second_min_size: 0,
+            enabled: true,
+            flags: None,
+            ex_flags: 0,
+            parent: None,
+        }
+    }
+
+    /// Returns the direction of the splitter
+    pub fn orientation(&self) -> SplitterOrientation {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.orientation.unwrap_or(SplitterOrientation::Vertical)
+    }
+
+    /// Returns true if the control user can drag the splitter, return false otherwise
+    pub fn enabled(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_enabled(handle) }
+    }
+
+    /// Enable or disable the control
+    pub fn set_enabled(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_enabled(handle, v) }
+    }
+
+    /// Returns true if the control is visible to the user. Will return true even if the
+    /// control is outside of the parent client view (ex: at the position (10000, 10000))
+    pub fn visible(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_visibility(handle) }
+    }
+
+    /// Show or hide the control to the user
+    pub fn set_visible(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_visibility(handle, v) }
+    }
+
+    /// Returns the size of the splitter in the parent window
+    pub fn size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_size(handle) }
+    }
+
+    /// Sets the size of the splitter in the parent window. The panes are not moved.
+    pub fn set_size(&self, x: u32, y: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_size(handle, x, y, false) }
+    }
+
+    /// Returns the position of the splitter in the parent window
+    pub fn position(&self) -> (i32, i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_position(handle) }
+    }
+
+    /// Sets the position of the splitter in the parent window. The panes are not moved.
+    pub fn set_position(&self, x: i32, y: i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_position(handle, x, y) }
+    }
+
+    /// Winapi class name used during control creation
+    pub fn class_name(&self) -> &'static str {
+        "NWG_SPLITTER"
+    }
+
+    /// Winapi base flags used during window creation
+    pub fn flags(&self) -> u32 {
+        WS_VISIBLE
+    }
+
+    /// Winapi flags required by the control
+    pub fn forced_flags(&self) -> u32 {
+        WS_CHILD
+    }
+
+    /// Track the mouse while the splitter is dragged and move the panes
+    fn hook_drag(&self, first: ControlHandle, second: ControlHandle, min_sizes: (u32, u32)) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_dpi::logical_to_physical;
+        use winapi::shared::windef::{HWND, POINT, RECT};
+        use winapi::um::winuser::{WM_SETCURSOR, WM_LBUTTONDOWN, WM_MOUSEMOVE, WM_LBUTTONUP, WM_CAPTURECHANGED, IDC_SIZEWE, IDC_SIZENS};
+        use winapi::um::winuser::{SetCursor, LoadCursorW, SetCapture, ReleaseCapture, GetCapture, GetCursorPos, GetWindowRect, ScreenToClient, MoveWindow};
+        use std::cell::Cell;
+        use std::{mem, ptr};
+
+        /// Rect of a window in the client coordinates of its parent
+        unsafe fn parent_rect(hwnd: HWND, parent: HWND) -> RECT {
+            let mut r: RECT = mem::zeroed();
+            GetWindowRect(hwnd, &mut r);
+
+            let mut top_left = POINT { x: r.left, y: r.top };
+            let mut bottom_right = POINT { x: r.right, y: r.bottom };
+            ScreenToClient(parent, &mut top_left);
+            ScreenToClient(parent, &mut bottom_right);
+
+            RECT { left: top_left.x, top: top_left.y, right: bottom_right.x, bottom: bottom_right.y }
+        }
+
+        let first = check_hwnd(&first, NOT_BOUND, BAD_HANDLE);
+        let second = check_hwnd(&second, NOT_BOUND, BAD_HANDLE);
+        let vertical = self.orientation() == SplitterOrientation::Vertical;
+
+        let (min_first, min_second) = unsafe { logical_to_physical(min_sizes.0 as i32, min_sizes.1 as i32) };
+
+        // Distance between the cursor and the splitter edge when the drag started
+        let drag_offset: Cell<Option<i32>> = Cell::new(None);
+
+        let handler0 = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, _w, _l| unsafe {
+            let parent = wh::get_window_parent(hwnd);
+
+            match msg {
+                WM_SETCURSOR => {
+                    let cursor = match vertical { true => IDC_SIZEWE, false => IDC_SIZENS };
+                    SetCursor(LoadCursorW(ptr::null_mut(), cursor));
+                    return Some(1);
+                },
+                WM_LBUTTONDOWN => {
+                    let mut cursor = POINT { x: 0, y: 0 };
+                    GetCursorPos(&mut cursor);
+                    ScreenToClient(parent, &mut cursor);
+
+                    let splitter = parent_rect(hwnd, parent);
+                    let offset = match vertical { true => cursor.x - splitter.left, false => cursor.y - splitter.top };
+                    drag_offset.set(Some(offset));
+                    SetCapture(hwnd);
+                },
+                WM_MOUSEMOVE => {
+                    let offset = match drag_offset.get() {
+                        Some(offset) if GetCapture() == hwnd => offset,
+                        _ => { return None; }
+                    };
+
+                    let mut cursor = POINT { x: 0, y: 0 };
+                    GetCursorPos(&mut cursor);
+                    ScreenToClient(parent, &mut cursor);
+
+                    let splitter = parent_rect(hwnd, parent);
+                    let first_rect = parent_rect(first, parent);
+                    let second_rect = parent_rect(second, parent);
+
+                    if vertical {
+                        let width = splitter.right - splitter.left;
+                        let max = second_rect.right - min_second - width;
+                        let min = first_rect.left + min_first;
+                        let x = i32::max(min, i32::min(max, cursor.x - offset));
+                        if x == splitter.left || min > max { return None; }
+
+                        MoveWindow(first, first_rect.left, first_rect.top, x - first_rect.left, first_rect.bottom - first_rect.top, 1);
+                        MoveWindow(hwnd, x, splitter.top, width, splitter.bottom - splitter.top, 1);
+                        MoveWindow(second, x + width, second_rect.top, second_rect.right - (x + width), second_rect.bottom - second_rect.top, 1);
+                    } else {
+                        let height = splitter.bottom - splitter.top;
+                        let max = second_rect.bottom - min_second - height;
+                        let min = first_rect.top + min_first;
+                        let y = i32::max(min, i32::min(max, cursor.y - offset));
+                        if y == splitter.top || min > max { return None; }
+
+                        MoveWindow(first, first_rect.left, first_rect.top, first_rect.right - first_rect.left, y - first_rect.top, 1);
+                        MoveWindow(hwnd, splitter.left, y, splitter.right - splitter.left, height, 1);
+                        MoveWindow(second, second_rect.left, y + height, second_rect.right - second_rect.left, second_rect.bottom - (y + height), 1);
+                    }
+                },
+                WM_LBUTTONUP => {
+                    if drag_offset.get().is_some() {
+                        ReleaseCapture();
+                    }
+                },
+                WM_CAPTURECHANGED => {
+                    drag_offset.set(None);
+                },
+                _ => {}
+            }
+
+            None
+        });
+
+        *self.handler0.borrow_mut() = Some(handler0.unwrap());
+    }
+
+}
+
+impl PartialEq for Splitter {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Drop for Splitter {
+    fn drop(&mut self) {
+        let handler = self.handler0.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        self.handle.destroy();
+    }
+}
+
+pub struct SplitterBuilder {
+    size: (i32, i32),
+    position: (i32, i32),
+    orientation: SplitterOrientation,
+    first: Option<ControlHandle>,
+    second: Option<ControlHandle>,
+    first_min_size: u32,
+    second_min_size: u32,
+    enabled: bool,
+    flags: Option<SplitterFlags>,
+    ex_flags: u32,
+    parent: Option<ControlHandle>
+}
+
+impl SplitterBuilder {
+
+    pub fn flags(mut self, flags: SplitterFlags) -> SplitterBuilder {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: u32) -> SplitterBuilder {
+        self.ex_flags = flags;
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> SplitterBuilder {
+        self.size = size;
+        self
+    }
+
+    pub fn position(mut self, pos: (i32, i32)) -> SplitterBuilder {
+        self.position = pos;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: SplitterOrientation) -> SplitterBuilder {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn first<C: Into<ControlHandle>>(mut self, pane: C) -> SplitterBuilder {
+        self.first = Some(pane.into());
+        self
+    }
+
+    pub fn second<C: Into<ControlHandle>>(mut self, pane: C) -> SplitterBuilder {
+        self.second = Some(pane.into());
+        self
+    }
+
+    pub fn first_min_size(mut self, size: u32) -> SplitterBuilder {
+        self.first_min_size = size;
+        self
+    }
+
+    pub fn second_min_size(mut self, size: u32) -> SplitterBuilder {
+        self.second_min_size = size;
+        self
+    }
+
+    pub fn enabled(mut self, e: bool) -> SplitterBuilder {
+        self.enabled = e;
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> SplitterBuilder {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut Splitter) -> Result<(), NwgError> {
+        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        let parent = match self.parent {
+            Some(p) => Ok(p),
+            None => Err(NwgError::no_parent("Splitter"))
+        }?;
+
+        let (first, second) = match (self.first, self.second) {
+            (Some(first), Some(second)) => Ok((first, second)),
+            _ => Err(NwgError::control_create("Splitter requires two panes (`first` and `second`)"))
+        }?;
+
+        *out = Default::default();
+
+        out.handle = ControlBase::build_hwnd()
+            .class_name(out.class_name())
+            .forced_flags(out.forced_flags())
+            .flags(flags)
+            .ex_flags(self.ex_flags)
+            .size(self.size)
+            .position(self.position)
+            .parent(Some(parent))
+            .build()?;
+
+        out.orientation = Some(self.orientation);
+        out.hook_drag(first, second, (self.first_min_size, self.second_min_size));
+        out.set_enabled(self.enabled);
+
+        Ok(())
+    }
+
+}
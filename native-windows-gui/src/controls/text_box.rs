@@ -53,6 +53,7 @@ Note: Use `\r\n` to input a new line not just `\n`.
   * `limit`:    The maximum number of character that can be inserted in the control
   * `readonly`: If the textbox should allow user input or not
   * `focus`:    The control receive focus after being created
+  * `word_wrap`: If true, long lines are wrapped to the width of the control. Removes the `AUTOHSCROLL` and `HSCROLL` flags.
 
 **Control events:**
   * `OnTextInput`: When a TextBox value is changed
@@ -90,6 +91,7 @@ impl TextBox {
             limit: 0,
             readonly: false,
             focus: false,
+            word_wrap: false,
             font: None,
             parent: None
         }
@@ -125,7 +127,29 @@ impl TextBox {
         wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32
     }
 
+    /**
+        Return the number of characters that can still be entered before reaching `limit`. Ex: for a character counter.
+        Returns `None` if the text box has no practical limit (`set_limit(0)`).
+
+        Without a call to `set_limit`, the limit is 32767 characters. Line breaks count as two characters (`\r\n`).
+    */
+    pub fn remaining_chars(&self) -> Option<u32> {
+        use winapi::um::winuser::WM_GETTEXTLENGTH;
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+
+        let limit = self.limit();
+        if limit >= 0x7FFFFFFE {
+            return None;
+        }
+
+        let length = wh::send_message(handle, WM_GETTEXTLENGTH, 0, 0) as u32;
+        Some(limit.saturating_sub(length))
+    }
+
     /// Set the number of maximum character allowed in this text input
+    /// If `limit` is 0, the text length is set to 0xFFFFFFFF characters
     pub fn set_limit(&self, limit: usize) {
         use winapi::um::winuser::EM_SETLIMITTEXT;
 
@@ -187,6 +211,85 @@ impl TextBox {
         wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
     }
 
+    /// Replace the selected text with `text`. If nothing is selected, `text` is inserted at the caret.
+    /// The operation can be reverted with `undo`. After the call, the caret is placed at the end of the inserted text.
+    pub fn replace_selection<'a>(&self, text: &'a str) {
+        use winapi::um::winuser::EM_REPLACESEL;
+        use crate::win32::base_helper::to_utf16;
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+
+        let text = to_utf16(text);
+        wh::send_message(handle, EM_REPLACESEL as u32, 1, text.as_ptr() as LPARAM);
+    }
+
+    /// Return the caret position as a zero based `(line, column)` pair.
+    /// Lines are counted as displayed by the control, so with word wrapping enabled a long line counts as many lines.
+    pub fn caret(&self) -> (u32, u32) {
+        use winapi::um::winuser::{EM_LINEFROMCHAR, EM_LINEINDEX};
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+
+        let index = self.selection().end;
+        let line = wh::send_message(handle, EM_LINEFROMCHAR as u32, index as WPARAM, 0) as u32;
+        let line_start = wh::send_message(handle, EM_LINEINDEX as u32, line as WPARAM, 0) as u32;
+
+        (line, index - line_start)
+    }
+
+    /// Move the caret to a zero based `(line, column)` pair and scroll it into view.
+    /// `line` is clamped to the last line and `column` is clamped to the line length, so the
+    /// caret never ends between the `\r` and the `\n` of a line ending.
+    pub fn set_caret(&self, line: u32, column: u32) {
+        use winapi::um::winuser::{EM_GETLINECOUNT, EM_LINEINDEX, EM_LINELENGTH, EM_SETSEL, EM_SCROLLCARET};
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+
+        let line_count = wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as u32;
+        let line = u32::min(line, line_count.saturating_sub(1));
+
+        let line_start = wh::send_message(handle, EM_LINEINDEX as u32, line as WPARAM, 0) as u32;
+        let line_length = wh::send_message(handle, EM_LINELENGTH as u32, line_start as WPARAM, 0) as u32;
+        let index = line_start + u32::min(column, line_length);
+
+        wh::send_message(handle, EM_SETSEL as u32, index as WPARAM, index as LPARAM);
+        wh::send_message(handle, EM_SCROLLCARET as u32, 0, 0);
+    }
+
+    /// Return the text of each line of the control, without the line breaks. An empty control returns a single empty line,
+    /// and a text ending with a line break ends with an empty line.
+    /// Lines are counted as displayed by the control, so with word wrapping enabled a long line is returned in many parts.
+    pub fn lines(&self) -> Vec<String> {
+        use winapi::um::winuser::{EM_GETLINECOUNT, EM_GETLINE, EM_LINEINDEX, EM_LINELENGTH};
+        use crate::win32::base_helper::{check_hwnd, from_utf16};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let line_count = wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as usize;
+        let mut lines = Vec::with_capacity(line_count);
+
+        for line in 0..line_count {
+            let line_start = wh::send_message(handle, EM_LINEINDEX as u32, line as WPARAM, 0);
+            let length = wh::send_message(handle, EM_LINELENGTH as u32, line_start as WPARAM, 0) as usize;
+            if length == 0 {
+                lines.push(String::new());
+                continue;
+            }
+
+            // EM_GETLINE reads the size of the buffer from its first character and does not add a null character
+            let mut buffer: Vec<u16> = vec![0; length];
+            buffer[0] = length.min(u16::max_value() as usize) as u16;
+            let copied = wh::send_message(handle, EM_GETLINE as u32, line as WPARAM, buffer.as_mut_ptr() as LPARAM) as usize;
+
+            lines.push(from_utf16(&buffer[0..copied.min(length)]));
+        }
+
+        lines
+    }
+
     /// Return the length of the user input in the control. This is better than test.len() as it
     /// does not allocate a string in memory
     pub fn len(&self) -> u32 {
@@ -217,6 +320,85 @@ impl TextBox {
         wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
     }
 
+    /// Return true if the long lines of the text box are wrapped to the width of the control.
+    /// Lines are wrapped when the text box has neither the `AUTOHSCROLL` nor the `HSCROLL` flag.
+    pub fn word_wrap(&self) -> bool {
+        use crate::win32::base_helper::check_hwnd;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & (ES_AUTOHSCROLL | WS_HSCROLL) == 0
+    }
+
+    /**
+        Enable or disable word wrapping. An edit control cannot change how it wraps its text after its creation,
+        so the text box is recreated in place with the same parent, position, size, z-order, flags, font, text,
+        selection, limit and modified flag. `AUTOHSCROLL` and `HSCROLL` are removed (`wrap=true`) or added (`wrap=false`).
+        Copying the text into the new control does not raise `OnTextInput`.
+
+        The text box `handle` is replaced by the handle of the new control. Because events use the current value of `handle`,
+        the events sent through the parent (ex: `OnTextInput`) keep working. Events handlers and raw events handlers bound to
+        the text box window itself (ex: by a `full_bind_event_handler` on the parent window, for `OnKeyPress`), tooltips
+        and layouts that use the old handle must be registered again.
+
+        Does nothing if the text box already wraps (or does not wrap) its lines.
+    */
+    pub fn set_word_wrap(&mut self, wrap: bool) -> Result<(), NwgError> {
+        use winapi::um::winuser::{EM_SETLIMITTEXT, EM_SETMODIFY, EM_SETSEL, EM_SCROLLCARET};
+        use crate::win32::base_helper::check_hwnd;
+        use crate::with_events_suppressed;
+
+        let old_handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if self.word_wrap() == wrap {
+            return Ok(());
+        }
+
+        let style = match wrap {
+            true => wh::get_style(old_handle) & !(ES_AUTOHSCROLL | WS_HSCROLL),
+            false => wh::get_style(old_handle) | ES_AUTOHSCROLL | WS_HSCROLL
+        };
+
+        let (x, y) = self.position();
+        let (w, h) = self.size();
+        let parent = ControlHandle::Hwnd(wh::get_window_parent(old_handle));
+        let font = wh::get_window_font(old_handle);
+        let (text, selection, limit, modified, focus) = (self.text(), self.selection(), self.limit(), self.modified(), self.focus());
+
+        let handle = ControlBase::build_hwnd()
+            .class_name(self.class_name())
+            .forced_flags(self.forced_flags())
+            .flags(style)
+            .ex_flags(wh::get_ex_style(old_handle))
+            .size((w as i32, h as i32))
+            .position((x, y))
+            .text("")
+            .parent(Some(parent))
+            .build()?;
+
+        let new_handle = handle.hwnd().expect(BAD_HANDLE);
+
+        // The limit must be set before the text, or text longer than the default limit would be truncated
+        wh::send_message(new_handle, EM_SETLIMITTEXT as u32, limit as WPARAM, 0);
+
+        unsafe {
+            wh::set_window_font(new_handle, if font.is_null() { None } else { Some(font) }, false);
+            with_events_suppressed(handle, || wh::set_window_text(new_handle, &text));
+            wh::set_window_after(new_handle, Some(old_handle));
+        }
+
+        wh::send_message(new_handle, EM_SETMODIFY as u32, modified as WPARAM, 0);
+        wh::send_message(new_handle, EM_SETSEL as u32, selection.start as WPARAM, selection.end as LPARAM);
+        wh::send_message(new_handle, EM_SCROLLCARET as u32, 0, 0);
+
+        if focus {
+            unsafe { wh::set_focus(new_handle); }
+        }
+
+        self.handle.destroy();
+        self.handle = handle;
+
+        Ok(())
+    }
+
     /// Remove all text from the textbox
     pub fn clear(&self) {
         self.set_text("");
@@ -340,6 +522,7 @@ pub struct TextBoxBuilder<'a> {
     limit: usize,
     readonly: bool,
     focus: bool,
+    word_wrap: bool,
     font: Option<&'a Font>,
     parent: Option<ControlHandle>
 }
@@ -386,6 +569,11 @@ impl<'a> TextBoxBuilder<'a> {
         self
     }
 
+    pub fn word_wrap(mut self, wrap: bool) -> TextBoxBuilder<'a> {
+        self.word_wrap = wrap;
+        self
+    }
+
     pub fn font(mut self, font: Option<&'a Font>) -> TextBoxBuilder<'a> {
         self.font = font;
         self
@@ -397,7 +585,10 @@ impl<'a> TextBoxBuilder<'a> {
     }
 
     pub fn build(self, out: &mut TextBox) -> Result<(), NwgError> {
-        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        if self.word_wrap {
+            flags &= !(ES_AUTOHSCROLL | WS_HSCROLL);
+        }
 
         let parent = match self.parent {
             Some(p) => Ok(p),
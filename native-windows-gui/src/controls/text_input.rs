@@ -1,16 +1,31 @@
 use winapi::shared::minwindef::{UINT, WPARAM, LPARAM};
+use winapi::shared::windef::HWND;
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, ES_NUMBER, ES_LEFT, ES_CENTER, ES_RIGHT, WS_TABSTOP, ES_AUTOHSCROLL};
 use crate::win32::window_helper as wh; 
 use crate::win32::base_helper::{check_hwnd, to_utf16};
 use crate::{Font, NwgError, HTextAlign, RawEventHandler};
-use super::{ControlBase, ControlHandle};
-use std::cell::RefCell;
+use super::{ControlBase, ControlHandle, WideBuffer};
+use std::cell::{Cell, RefCell};
 use std::ops::Range;
+use std::str::FromStr;
+use std::collections::HashSet;
+use std::rc::Rc;
 use std::char;
 
 const NOT_BOUND: &'static str = "TextInput is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: TextInput handle is not HWND!";
 
+/// Id of the timer that raises `OnTextInputDebounced`
+const DEBOUNCE_TIMER: usize = 0xFFF1;
+
+/// Width of the up-down control created by `attach_spinner`
+const SPINNER_WIDTH: i32 = 18;
+
+thread_local! {
+    /// Text inputs with a change that was not yet raised in `OnTextInputDebounced`
+    static DEBOUNCE_PENDING: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
 
 bitflags! {
     /**
@@ -32,6 +47,62 @@ bitflags! {
     }
 }
 
+/**
+    Restrict the values that can be entered in a TextInput. See `TextInput::set_mask`.
+
+    * Integer:   An optional minus sign followed by digits
+    * Decimal:   An optional minus sign followed by digits, with an optional `.` separator followed by digits. Ex: `-1.5`.
+                 Incomplete values such as `1.` are accepted while typing, but not when the control loses the focus.
+    * Digits(n): Exactly `n` digits
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputMask {
+    Integer,
+    Decimal,
+    Digits(u32),
+}
+
+impl InputMask {
+
+    /// Check if `text` could become a valid value if the user keeps typing.
+    pub fn accepts_partial(&self, text: &str) -> bool {
+        match *self {
+            InputMask::Integer => {
+                let digits = text.strip_prefix('-').unwrap_or(text);
+                digits.chars().all(|c| c.is_ascii_digit())
+            },
+            InputMask::Decimal => {
+                let digits = text.strip_prefix('-').unwrap_or(text);
+                digits.chars().all(|c| c.is_ascii_digit() || c == '.') && digits.matches('.').count() <= 1
+            },
+            InputMask::Digits(n) => {
+                text.chars().all(|c| c.is_ascii_digit()) && text.len() <= n as usize
+            }
+        }
+    }
+
+    /// Check if `text` is a complete value. An empty text is always accepted.
+    pub fn accepts(&self, text: &str) -> bool {
+        if text.is_empty() {
+            return true;
+        }
+
+        match *self {
+            InputMask::Integer => {
+                self.accepts_partial(text) && text.chars().any(|c| c.is_ascii_digit())
+            },
+            InputMask::Decimal => {
+                let digits = text.strip_prefix('-').unwrap_or(text);
+                self.accepts_partial(text) && digits.split('.').all(|part| !part.is_empty())
+            },
+            InputMask::Digits(n) => {
+                self.accepts_partial(text) && text.len() == n as usize
+            }
+        }
+    }
+
+}
+
 /** 
 An edit control is a rectangular control window to permit the user to enter and edit text by typing on the keyboard
 This control only allow a single line input. For block of text, use `TextBox`.
@@ -53,9 +124,15 @@ TextInput is not behind any features.
   * `align`:            The alignment of the text in the text input
   * `background_color`: The color of the textinput top and bottom padding. This is not the white background under the text.
   * `focus`:            The control receive focus after being created
+  * `mask`:             Restrict the values that can be entered in the control
+  * `wheel_step`:       The value added or removed from the control number when the mouse wheel is used
+  * `wheel_range`:      The `(min, max)` bounds of the values set with the mouse wheel
+  * `debounce`:         The delay, in milliseconds, without changes before `OnTextInputDebounced` is raised
 
 **Control events:**
   * `OnTextInput`: When a TextInput value is changed
+  * `OnTextInputDebounced`: When the value stopped changing for the `debounce` delay, or when the control loses the focus with a pending change
+  * `OnValidationFailed`: When the control loses the focus and its value does not match the input mask
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -75,6 +152,22 @@ fn build_box(tbox: &mut nwg::TextInput, window: &nwg::Window, font: &nwg::Font)
 pub struct TextInput {
     pub handle: ControlHandle,
     handler0: RefCell<Option<RawEventHandler>>,
+    handler1: RefCell<Option<RawEventHandler>>,
+    handler2: RefCell<Option<RawEventHandler>>,
+    debounce_parent_handler: RefCell<Option<RawEventHandler>>,
+    handler3: RefCell<Option<RawEventHandler>>,
+    mask: Rc<Cell<Option<InputMask>>>,
+    wheel_step: Rc<Cell<Option<f64>>>,
+    wheel_range: Rc<Cell<Option<(f64, f64)>>>,
+    debounce: Rc<Cell<Option<u32>>>,
+    spinner: RefCell<Option<Spinner>>,
+}
+
+/// An up-down control attached to a text input with `TextInput::attach_spinner`
+struct Spinner {
+    handle: ControlHandle,
+    handler0: RawEventHandler,
+    handler1: RawEventHandler,
 }
 
 impl TextInput {
@@ -95,6 +188,10 @@ impl TextInput {
             font: None,
             parent: None,
             background_color: None,
+            mask: None,
+            wheel_step: None,
+            wheel_range: None,
+            debounce: None,
         }
     }
 
@@ -145,6 +242,25 @@ impl TextInput {
         wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32
     }
 
+    /**
+        Return the number of characters that can still be entered before reaching `limit`. Ex: for a character counter.
+        Returns `None` if the text input has no practical limit (`set_limit(0)`).
+
+        Without a call to `set_limit`, the limit is 32767 characters.
+    */
+    pub fn remaining_chars(&self) -> Option<u32> {
+        use winapi::um::winuser::WM_GETTEXTLENGTH;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let limit = self.limit();
+        if limit >= 0x7FFFFFFE {
+            return None;
+        }
+
+        let length = wh::send_message(handle, WM_GETTEXTLENGTH, 0, 0) as u32;
+        Some(limit.saturating_sub(length))
+    }
+
     /// Set the number of maximum character allowed in this text input
     /// If `limit` is 0, the text length is set to 0x7FFFFFFE characters 
     pub fn set_limit(&self, limit: usize) {
@@ -177,6 +293,159 @@ impl TextInput {
         wh::send_message(handle, EM_UNDO as u32, 0, 0);
     }
 
+    /// Return the input mask of the text input
+    pub fn mask(&self) -> Option<InputMask> {
+        self.mask.get()
+    }
+
+    /// Set the input mask of the text input. Characters typed or pasted that would not match the mask are rejected,
+    /// and `OnValidationFailed` is raised if the value is incomplete when the control loses the focus.
+    /// Text set programmatically is not validated. Pasted text is only checked with the `clipboard` feature,
+    /// otherwise it is validated when the control loses the focus.
+    pub fn set_mask(&self, mask: Option<InputMask>) {
+        self.mask.set(mask);
+        self.update_wheel_hook();
+    }
+
+    /// Return the delay, in milliseconds, before `OnTextInputDebounced` is raised
+    pub fn debounce(&self) -> Option<u32> {
+        self.debounce.get()
+    }
+
+    /**
+        Raise `OnTextInputDebounced` once the value stopped changing for `delay` milliseconds. Each change restarts the delay.
+        Ex: to run a search as the user types without running it for every key.
+
+        A pending change is raised right away when the control loses the focus or when the delay is removed,
+        so the last value is never lost. `OnTextInput` is still raised for every change. Set `None` to disable the event.
+    */
+    pub fn set_debounce(&self, delay: Option<u32>) {
+        use crate::unbind_raw_event_handler;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.debounce.set(delay);
+
+        match delay {
+            Some(_) => if self.debounce_parent_handler.borrow().is_none() {
+                self.hook_debounce();
+            },
+            None => {
+                flush_debounce(handle);
+
+                if let Some(h) = self.debounce_parent_handler.borrow_mut().take() {
+                    drop(unbind_raw_event_handler(&h));
+                }
+
+                if let Some(h) = self.handler3.borrow_mut().take() {
+                    drop(unbind_raw_event_handler(&h));
+                }
+            }
+        }
+    }
+
+    /// Return the value added or removed from the control number when the mouse wheel is used
+    pub fn wheel_step(&self) -> Option<f64> {
+        self.wheel_step.get()
+    }
+
+    /**
+        Make the number in the control adjustable with the mouse wheel. Each notch of the wheel adds or removes `step` from the value.
+        The value is formatted and bounded using the input mask (ex: `InputMask::Integer` rounds the value, `InputMask::Digits(2)` stops at `99`).
+        An empty control counts as `0`. The wheel is ignored if the control has no input mask or if the text is not a number.
+
+        Changing the value raises `OnTextInput`. Set `None` to disable (the default).
+    */
+    pub fn set_wheel_step(&self, step: Option<f64>) {
+        self.wheel_step.set(step);
+        self.update_wheel_hook();
+    }
+
+    /// Return the `(min, max)` bounds of the values set with the mouse wheel
+    pub fn wheel_range(&self) -> Option<(f64, f64)> {
+        self.wheel_range.get()
+    }
+
+    /// Set the `(min, max)` bounds of the values set with the mouse wheel. Values typed by the user are not affected.
+    pub fn set_wheel_range(&self, range: Option<(f64, f64)>) {
+        self.wheel_range.set(range);
+    }
+
+    /// Return `true` if an up-down control was attached with `attach_spinner`
+    pub fn spinner(&self) -> bool {
+        self.spinner.borrow().is_some()
+    }
+
+    /**
+        Attach an up-down control (two arrows) on the right side of the text input. Each click on the arrows, or each press on the up and down keys,
+        adds or removes `step` from the number in the control. The value is kept in `range` (`(min, max)`) and formatted using the input mask.
+        An empty control counts as `0`. If the text is not a number, the arrows are ignored.
+
+        The text input is narrowed to make room for the arrows. The arrows follow the text input when it is moved, resized, hidden or disabled.
+        Changing the value raises `OnTextInput`. Calling this function again replaces the range and the step.
+    */
+    pub fn attach_spinner(&self, range: (f64, f64), step: f64) -> Result<(), NwgError> {
+        use winapi::um::winuser::{WS_CHILD, WS_VISIBLE, WS_DISABLED};
+        use winapi::um::commctrl::{UPDOWN_CLASS, UDS_ALIGNRIGHT, UDS_ARROWKEYS, UDS_HOTTRACK, UDS_NOTHOUSANDS, UDM_SETBUDDY, UDM_SETRANGE32, UDM_SETPOS32};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.detach_spinner();
+
+        let (_, height) = unsafe { wh::get_window_size(handle) };
+        let mut flags = WS_CHILD | UDS_ALIGNRIGHT | UDS_ARROWKEYS | UDS_HOTTRACK | UDS_NOTHOUSANDS;
+        if self.visible() { flags |= WS_VISIBLE; }
+        if !self.enabled() { flags |= WS_DISABLED; }
+
+        let mut spinner_handle = ControlBase::build_hwnd()
+            .class_name(UPDOWN_CLASS)
+            .text("")
+            .flags(flags)
+            .size((SPINNER_WIDTH, height as i32))
+            .parent(Some(ControlHandle::Hwnd(wh::get_window_parent(handle))))
+            .build()?;
+
+        // The position never reaches the bounds so the arrows are always enabled. The value is stored in the text.
+        let spinner_hwnd = spinner_handle.hwnd().unwrap();
+        wh::send_message(spinner_hwnd, UDM_SETRANGE32, 0, 100);
+        wh::send_message(spinner_hwnd, UDM_SETPOS32, 0, 50);
+        wh::send_message(spinner_hwnd, UDM_SETBUDDY, handle as WPARAM, 0);
+
+        match self.hook_spinner(spinner_hwnd, range, step) {
+            Ok((handler0, handler1)) => {
+                *self.spinner.borrow_mut() = Some(Spinner { handle: spinner_handle, handler0, handler1 });
+                Ok(())
+            },
+            Err(e) => {
+                spinner_handle.destroy();
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove the up-down control attached with `attach_spinner`. The text input gets back the space used by the arrows.
+    pub fn detach_spinner(&self) {
+        use crate::unbind_raw_event_handler;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut spinner = match self.spinner.borrow_mut().take() {
+            Some(s) => s,
+            None => { return; }
+        };
+
+        drop(unbind_raw_event_handler(&spinner.handler0));
+        drop(unbind_raw_event_handler(&spinner.handler1));
+
+        unsafe {
+            let spinner_hwnd = spinner.handle.hwnd().unwrap();
+            let (x, _) = wh::get_window_position(handle);
+            let (_, height) = wh::get_window_size(handle);
+            let (spinner_x, _) = wh::get_window_position(spinner_hwnd);
+            let (spinner_width, _) = wh::get_window_size(spinner_hwnd);
+            wh::set_window_size(handle, ((spinner_x - x) + spinner_width as i32) as u32, height, false);
+        }
+
+        spinner.handle.destroy();
+    }
+
     /// Return the selected range of characters by the user in the text input
     pub fn selection(&self) -> Range<u32> {
         use winapi::um::winuser::EM_GETSEL;
@@ -200,6 +469,16 @@ impl TextInput {
         wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
     }
 
+    /// Replace the selected text with `text`. If nothing is selected, `text` is inserted at the caret.
+    /// The operation can be reverted with `undo`. After the call, the caret is placed at the end of the inserted text.
+    pub fn replace_selection<'a>(&self, text: &'a str) {
+        use winapi::um::winuser::EM_REPLACESEL;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let text = to_utf16(text);
+        wh::send_message(handle, EM_REPLACESEL as u32, 1, text.as_ptr() as LPARAM);
+    }
+
     /// Return the length of the user input in the control. This is better than `input.text().len()` as it
     /// does not allocate a string in memory
     pub fn len(&self) -> u32 {
@@ -291,6 +570,14 @@ impl TextInput {
         unsafe { wh::get_window_text(handle) }
     }
 
+    /**
+        Parse the text displayed in the TextInput. Leading and trailing whitespaces are ignored.
+        Numbers must use `.` as the decimal separator, as in Rust. Ex: `let age: u32 = input.text_as()?;`
+    */
+    pub fn text_as<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.text().trim().parse()
+    }
+
     /// Set the text displayed in the TextInput
     pub fn set_text<'a>(&self, v: &'a str) {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -302,18 +589,12 @@ impl TextInput {
     /// as long as the user specified, however it might be longer or shorter than
     /// the actual placeholder text.
     pub fn placeholder_text<'a>(&self, text_length: usize) -> String { 
-        use std::ffi::OsString;
-        use std::os::windows::ffi::OsStringExt;
-        use winapi::shared::ntdef::WCHAR;
         use winapi::um::commctrl::EM_GETCUEBANNER;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut placeholder_text: Vec<WCHAR> = Vec::with_capacity(text_length);
-        unsafe {
-            placeholder_text.set_len(text_length);
-            wh::send_message(handle, EM_GETCUEBANNER, placeholder_text.as_mut_ptr() as WPARAM, placeholder_text.len() as LPARAM);
-            OsString::from_wide(&placeholder_text).into_string().unwrap_or("".to_string())
-        }
+        let mut placeholder_text = WideBuffer::new(text_length);
+        wh::send_message(handle, EM_GETCUEBANNER, placeholder_text.as_wparam(), placeholder_text.len() as LPARAM);
+        placeholder_text.to_string()
     }
 
     /// Set the placeholder text displayed in the TextInput
@@ -323,8 +604,8 @@ impl TextInput {
     
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         let placeholder_text = v.unwrap_or("");
-        let text = to_utf16(placeholder_text);
-        wh::send_message(handle, EM_SETCUEBANNER, 0, text.as_ptr() as LPARAM);
+        let mut text = WideBuffer::from_str(placeholder_text);
+        wh::send_message(handle, EM_SETCUEBANNER, 0, text.as_lparam());
     }
 
     /// Winapi class name used during control creation
@@ -444,6 +725,303 @@ impl TextInput {
         }
     }
 
+    /// Validate the user input against the input mask
+    fn hook_input_mask(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::NWG_VALIDATION_FAILED;
+        use winapi::um::winuser::{WM_CHAR, WM_PASTE, WM_KILLFOCUS, PostMessageW};
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        self.handle.hwnd().expect(BAD_HANDLE);
+
+        let mask = self.mask.clone();
+        let handler = bind_raw_event_handler_inner(&self.handle, 1, move |hwnd, msg, w, _l| {
+            let mask = match mask.get() {
+                Some(m) => m,
+                None => { return None; }
+            };
+
+            match msg {
+                WM_CHAR => {
+                    // Control characters (backspace, ctrl+c, ctrl+v, ...) are not text
+                    let c = w as u32;
+                    if c < 0x20 || c == 0x7F {
+                        return None;
+                    }
+
+                    let c = char::from_u32(c).unwrap_or('?').to_string();
+                    match mask.accepts_partial(&replace_selection(hwnd, &c)) {
+                        true => None,
+                        false => Some(0)
+                    }
+                },
+                WM_PASTE => match pasted_text() {
+                    Some(text) if !mask.accepts_partial(&replace_selection(hwnd, &text)) => Some(0),
+                    _ => None
+                },
+                WM_KILLFOCUS => {
+                    let text = unsafe { wh::get_window_text(hwnd) };
+                    if !mask.accepts(&text) {
+                        unsafe { PostMessageW(hwnd, NWG_VALIDATION_FAILED, 0, 0); }
+                    }
+                    None
+                },
+                _ => None
+            }
+        });
+
+        *self.handler1.borrow_mut() = Some(handler.unwrap());
+    }
+
+    /// Install the mouse wheel hook if the control has both an input mask and a wheel step, remove it otherwise
+    fn update_wheel_hook(&self) {
+        use crate::unbind_raw_event_handler;
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        match self.mask.get().is_some() && self.wheel_step.get().is_some() {
+            true => if self.handler2.borrow().is_none() {
+                self.hook_wheel_step();
+            },
+            false => if let Some(h) = self.handler2.borrow_mut().take() {
+                drop(unbind_raw_event_handler(&h));
+            }
+        }
+    }
+
+    /// Change the control number when the mouse wheel is used
+    fn hook_wheel_step(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_MOUSEWHEEL, WHEEL_DELTA, GET_WHEEL_DELTA_WPARAM};
+
+        if self.handle.blank() { panic!(NOT_BOUND); }
+        self.handle.hwnd().expect(BAD_HANDLE);
+
+        let mask = self.mask.clone();
+        let wheel_step = self.wheel_step.clone();
+        let wheel_range = self.wheel_range.clone();
+        let handler = bind_raw_event_handler_inner(&self.handle, 2, move |hwnd, msg, w, _l| {
+            if msg != WM_MOUSEWHEEL {
+                return None;
+            }
+
+            let (step, mask) = match (wheel_step.get(), mask.get()) {
+                (Some(s), Some(m)) => (s, m),
+                _ => { return None; }
+            };
+
+            let notches = GET_WHEEL_DELTA_WPARAM(w) as f64 / WHEEL_DELTA as f64;
+            match step_value(hwnd, Some(mask), step, notches, wheel_range.get()) {
+                true => Some(0),
+                false => None
+            }
+        });
+
+        *self.handler2.borrow_mut() = Some(handler.unwrap());
+    }
+
+    /// Change the control number when the arrows of the spinner are used, and keep the arrows next to the control
+    fn hook_spinner(&self, spinner: HWND, range: (f64, f64), step: f64) -> Result<(RawEventHandler, RawEventHandler), NwgError> {
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler};
+        use winapi::um::winuser::{WM_NOTIFY, WM_WINDOWPOSCHANGED, WM_ENABLE, WINDOWPOS, NMHDR, SWP_NOSIZE, SWP_NOMOVE,
+          SWP_SHOWWINDOW, SWP_HIDEWINDOW, EnableWindow};
+        use winapi::um::commctrl::{UDN_DELTAPOS, UDM_SETBUDDY, NMUPDOWN};
+        use winapi::shared::basetsd::UINT_PTR;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+
+        // UDN_DELTAPOS is sent to the parent of the up-down control
+        let mask = self.mask.clone();
+        let handler0 = bind_raw_event_handler_inner(&parent_handle, spinner as UINT_PTR, move |_hwnd, msg, _w, l| {
+            if msg == WM_NOTIFY {
+                let nmhdr = unsafe { &*(l as *const NMHDR) };
+                if nmhdr.hwndFrom == spinner && nmhdr.code == UDN_DELTAPOS {
+                    let data = unsafe { &*(l as *const NMUPDOWN) };
+                    step_value(handle, mask.get(), step, data.iDelta as f64, Some(range));
+
+                    // Keep the position of the up-down control
+                    return Some(1);
+                }
+            }
+
+            None
+        })?;
+
+        // Move the arrows with the control. Aligning the arrows again after a resize narrows the control to make room for them.
+        let aligning = Cell::new(false);
+        let handler1 = bind_raw_event_handler_inner(&self.handle, 4, move |hwnd, msg, w, l| {
+            match msg {
+                WM_WINDOWPOSCHANGED if !aligning.get() => unsafe {
+                    let pos = &*(l as *const WINDOWPOS);
+                    if pos.flags & SWP_NOSIZE == 0 {
+                        aligning.set(true);
+                        wh::send_message(spinner, UDM_SETBUDDY, hwnd as WPARAM, 0);
+                        aligning.set(false);
+                    } else if pos.flags & SWP_NOMOVE == 0 {
+                        let (x, y) = wh::get_window_position(hwnd);
+                        let (width, _) = wh::get_window_size(hwnd);
+                        wh::set_window_position(spinner, x + width as i32, y);
+                    }
+
+                    if pos.flags & SWP_SHOWWINDOW == SWP_SHOWWINDOW {
+                        wh::set_window_visibility(spinner, true);
+                    } else if pos.flags & SWP_HIDEWINDOW == SWP_HIDEWINDOW {
+                        wh::set_window_visibility(spinner, false);
+                    }
+                },
+                WM_ENABLE => unsafe { EnableWindow(spinner, w as i32); },
+                _ => {}
+            }
+
+            None
+        });
+
+        match handler1 {
+            Ok(handler1) => Ok((handler0, handler1)),
+            Err(e) => {
+                drop(unbind_raw_event_handler(&handler0));
+                Err(e)
+            }
+        }
+    }
+
+    /// Restart the debounce timer when the text changes and raise `OnTextInputDebounced` when it expires.
+    /// The timer uses a timer procedure, so its `WM_TIMER` messages never reach the events handlers.
+    fn hook_debounce(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_COMMAND, WM_KILLFOCUS, EN_CHANGE, SetTimer};
+        use winapi::shared::{basetsd::UINT_PTR, minwindef::HIWORD};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+
+        // EN_CHANGE is sent to the parent of the control
+        let debounce = self.debounce.clone();
+        let debounce_parent_handler = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |_hwnd, msg, w, l| {
+            if msg == WM_COMMAND && l as HWND == handle && HIWORD(w as u32) == EN_CHANGE {
+                if let Some(delay) = debounce.get() {
+                    DEBOUNCE_PENDING.with(|pending| pending.borrow_mut().insert(handle as usize));
+                    unsafe { SetTimer(handle, DEBOUNCE_TIMER, delay, Some(debounce_timer)); }
+                }
+            }
+
+            None
+        });
+
+        let handler3 = bind_raw_event_handler_inner(&self.handle, 3, move |hwnd, msg, _w, _l| {
+            if msg == WM_KILLFOCUS {
+                flush_debounce(hwnd);
+            }
+
+            None
+        });
+
+        *self.debounce_parent_handler.borrow_mut() = Some(debounce_parent_handler.unwrap());
+        *self.handler3.borrow_mut() = Some(handler3.unwrap());
+    }
+
+}
+
+/// Raise `OnTextInputDebounced` right away if the text input `hwnd` has a pending change
+fn flush_debounce(hwnd: HWND) {
+    use crate::win32::window_helper::NWG_DEBOUNCED;
+    use winapi::um::winuser::{KillTimer, PostMessageW};
+
+    let pending = DEBOUNCE_PENDING.with(|pending| pending.borrow_mut().remove(&(hwnd as usize)));
+    if pending {
+        unsafe {
+            KillTimer(hwnd, DEBOUNCE_TIMER);
+            PostMessageW(hwnd, NWG_DEBOUNCED, 0, 0);
+        }
+    }
+}
+
+unsafe extern "system" fn debounce_timer(hwnd: HWND, _msg: UINT, _id: usize, _time: u32) {
+    flush_debounce(hwnd);
+}
+
+/// Add `count` times `step` to the number in the edit control `hwnd`, keep it in `range` and in the values allowed by `mask`, and format it using `mask`.
+/// An empty control counts as `0`. Returns `false` if the text is not a number.
+fn step_value(hwnd: HWND, mask: Option<InputMask>, step: f64, count: f64, range: Option<(f64, f64)>) -> bool {
+    let text = unsafe { wh::get_window_text(hwnd) };
+    let text = text.trim();
+    let value = match text.is_empty() {
+        true => 0.0,
+        false => match parse_number(text) {
+            Some(v) => v,
+            None => { return false; }
+        }
+    };
+
+    let mut value = value + (step * count);
+    if let Some((min, max)) = range {
+        value = value.max(min).min(max);
+    }
+
+    if let Some(InputMask::Digits(n)) = mask {
+        value = value.max(0.0).min(10f64.powi(n as i32) - 1.0);
+    }
+
+    let precision = decimals(text).max(decimals(&step.to_string()));
+    let new_text = match mask {
+        Some(InputMask::Integer) => format!("{}", value.round() as i64),
+        Some(InputMask::Digits(n)) => format!("{:0width$}", value.round() as u64, width = n as usize),
+        Some(InputMask::Decimal) | None => format!("{:.*}", precision, value),
+    };
+
+    unsafe { wh::set_window_text(hwnd, &new_text); }
+
+    true
+}
+
+/// Parse a number, including the incomplete values accepted while the user types (ex: `1.`, `.5`, `-.`)
+fn parse_number(text: &str) -> Option<f64> {
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, text)
+    };
+
+    let mut number = format!("0{}", digits);
+    if number.ends_with('.') {
+        number.push('0');
+    }
+
+    number.parse::<f64>().ok().map(|v| sign * v)
+}
+
+/// Return the number of digits after the decimal separator in `text`
+fn decimals(text: &str) -> usize {
+    text.split('.').nth(1).map(|d| d.len()).unwrap_or(0)
+}
+
+/// Return the text of the edit control `hwnd` if its selection was replaced by `value`
+fn replace_selection(hwnd: HWND, value: &str) -> String {
+    use winapi::um::winuser::EM_GETSEL;
+    use winapi::shared::minwindef::DWORD;
+
+    let mut start: DWORD = 0;
+    let mut end: DWORD = 0;
+    wh::send_message(hwnd, EM_GETSEL as UINT, &mut start as *mut DWORD as WPARAM, &mut end as *mut DWORD as LPARAM);
+
+    let text: Vec<u16> = unsafe { wh::get_window_text(hwnd) }.encode_utf16().collect();
+    let start = (start as usize).min(text.len());
+    let end = (end as usize).max(start).min(text.len());
+
+    let mut new_text = text[0..start].to_vec();
+    new_text.extend(value.encode_utf16());
+    new_text.extend_from_slice(&text[end..]);
+
+    String::from_utf16_lossy(&new_text)
+}
+
+/// Return the text that would be pasted in a text input. Without the `clipboard` feature, the text cannot be read.
+fn pasted_text() -> Option<String> {
+    #[cfg(feature = "clipboard")]
+    { crate::Clipboard::text() }
+
+    #[cfg(not(feature = "clipboard"))]
+    { None }
 }
 
 impl Drop for TextInput {
@@ -454,6 +1032,36 @@ impl Drop for TextInput {
         if let Some(h) = handler.as_ref() {
             drop(unbind_raw_event_handler(h));
         }
+
+        let handler = self.handler1.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        let handler = self.handler2.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        let handler = self.debounce_parent_handler.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        let handler = self.handler3.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        if let Some(hwnd) = self.handle.hwnd() {
+            DEBOUNCE_PENDING.with(|pending| pending.borrow_mut().remove(&(hwnd as usize)));
+        }
+
+        if let Some(mut spinner) = self.spinner.borrow_mut().take() {
+            drop(unbind_raw_event_handler(&spinner.handler0));
+            drop(unbind_raw_event_handler(&spinner.handler1));
+            spinner.handle.destroy();
+        }
         
         self.handle.destroy();
     }
@@ -474,6 +1082,10 @@ pub struct TextInputBuilder<'a> {
     parent: Option<ControlHandle>,
     background_color: Option<[u8; 3]>,
     focus: bool,
+    mask: Option<InputMask>,
+    wheel_step: Option<f64>,
+    wheel_range: Option<(f64, f64)>,
+    debounce: Option<u32>,
 }
 
 impl<'a> TextInputBuilder<'a> {
@@ -543,6 +1155,26 @@ impl<'a> TextInputBuilder<'a> {
         self
     }
 
+    pub fn mask(mut self, mask: Option<InputMask>) -> TextInputBuilder<'a> {
+        self.mask = mask;
+        self
+    }
+
+    pub fn wheel_step(mut self, step: Option<f64>) -> TextInputBuilder<'a> {
+        self.wheel_step = step;
+        self
+    }
+
+    pub fn wheel_range(mut self, range: Option<(f64, f64)>) -> TextInputBuilder<'a> {
+        self.wheel_range = range;
+        self
+    }
+
+    pub fn debounce(mut self, delay: Option<u32>) -> TextInputBuilder<'a> {
+        self.debounce = delay;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> TextInputBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -582,6 +1214,11 @@ impl<'a> TextInputBuilder<'a> {
             .build()?;
 
         out.hook_non_client_size(self.background_color);
+        out.hook_input_mask();
+        out.set_mask(self.mask);
+        out.set_wheel_step(self.wheel_step);
+        out.set_wheel_range(self.wheel_range);
+        out.set_debounce(self.debounce);
 
         if self.limit > 0 {
             out.set_limit(self.limit);
@@ -33,6 +33,11 @@ as Tooltip as a standalone toolip, but more like a manager.
 
 A tooltip can support static text using `register` and dynamic text using `register_callback`.
 
+A tooltip can be displayed as a cartoon-style "balloon" using `balloon`. To show a bold title line with an icon, use `decoration` or `default_decoration`.
+Text with line breaks (`\n`) is only displayed on multiple lines if the tooltip has a maximum width (see `set_max_width`).
+The time before a tooltip appears and the time it stays visible can be changed with `set_delay_time` and `set_duration`.
+The delay before the tooltip of another control appears when the mouse moves between controls can be changed with `set_reshow_time`.
+
 Tooltip requires the `tooltip` features
 
 Example:
@@ -99,7 +104,12 @@ impl Tooltip {
             ico: None,
             default_ico: None,
             register: Vec::new(),
-            register_cb: Vec::new()
+            register_cb: Vec::new(),
+            balloon: false,
+            max_width: None,
+            delay_time: None,
+            duration: None,
+            reshow_time: None,
         }
     }
 
@@ -242,6 +252,51 @@ impl Tooltip {
         wh::send_message(handle, TTM_POP, 0, 0);
     }
 
+    /// Return `true` if the tooltip is displayed as a balloon
+    pub fn balloon(&self) -> bool {
+        use winapi::um::commctrl::TTS_BALLOON;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & TTS_BALLOON == TTS_BALLOON
+    }
+
+    /// Display the tooltip as a balloon (`true`) or as a standard rectangle (`false`)
+    pub fn set_balloon(&self, v: bool) {
+        use winapi::um::commctrl::TTS_BALLOON;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let style = wh::get_style(handle);
+        match v {
+            true => wh::set_style(handle, style | TTS_BALLOON),
+            false => wh::set_style(handle, style & !TTS_BALLOON),
+        }
+    }
+
+    /// Return the maximum width of the tooltip in pixels. Return `None` if the text is not wrapped.
+    pub fn max_width(&self) -> Option<u32> {
+        use winapi::um::commctrl::TTM_GETMAXTIPWIDTH;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match wh::send_message(handle, TTM_GETMAXTIPWIDTH, 0, 0) as i32 {
+            -1 => None,
+            w => Some(w as u32)
+        }
+    }
+
+    /// Set the maximum width of the tooltip in pixels. Longer text is wrapped on multiple lines and line breaks are honored.
+    /// Set the value to `None` to display the text on a single line.
+    pub fn set_max_width(&self, width: Option<u32>) {
+        use winapi::um::commctrl::TTM_SETMAXTIPWIDTH;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let value = match width {
+            Some(w) => w as i32,
+            None => -1
+        };
+
+        wh::send_message(handle, TTM_SETMAXTIPWIDTH, 0, value as LPARAM);
+    }
+
     /// Return the number of controls registered by the tooltip
     pub fn count(&self) -> usize {
         use winapi::um::commctrl::{TTM_GETTOOLCOUNT};
@@ -250,26 +305,43 @@ impl Tooltip {
         wh::send_message(handle, TTM_GETTOOLCOUNT, 0, 0) as usize
     }
 
-    /// Set the delay time for the tooltip to spawn in milliseconds
-    /// Set the value to `None` to reset the value to default
+    /// Set the delay time for the tooltip to spawn in milliseconds, once the mouse stopped over a control.
+    /// Set the value to `None` to reset the value to default (the system double click time)
     pub fn set_delay_time(&self, delay: Option<u16>) {
-        use winapi::um::commctrl::{TTDT_INITIAL, TTM_SETDELAYTIME};
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let value = match delay {
-            Some(d) => d & 0xFFFF,
-            None => u16::max_value() & 0xFFFF,
-        };
-
-        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_INITIAL as WPARAM, value as LPARAM);
+        use winapi::um::commctrl::TTDT_INITIAL;
+        self.set_timing(TTDT_INITIAL, delay);
     }
 
     /// Return the delay time of the tooltip in milliseconds
     pub fn delay_time(&self) -> u16 {
-        use winapi::um::commctrl::{TTDT_INITIAL, TTM_GETDELAYTIME};
+        use winapi::um::commctrl::TTDT_INITIAL;
+        self.timing(TTDT_INITIAL)
+    }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TTM_GETDELAYTIME, TTDT_INITIAL as WPARAM, 0) as u16
+    /// Set the time, in milliseconds, the tooltip stays visible if the mouse does not move. Ex: to leave more time to read a long text.
+    /// The value is capped at 32767 milliseconds by Windows. Set the value to `None` to reset the value to default (ten times the delay time)
+    pub fn set_duration(&self, duration: Option<u16>) {
+        use winapi::um::commctrl::TTDT_AUTOPOP;
+        self.set_timing(TTDT_AUTOPOP, duration);
+    }
+
+    /// Return the time the tooltip stays visible in milliseconds
+    pub fn duration(&self) -> u16 {
+        use winapi::um::commctrl::TTDT_AUTOPOP;
+        self.timing(TTDT_AUTOPOP)
+    }
+
+    /// Set the delay, in milliseconds, before the tooltip of another control is displayed when the mouse moves from a control to another.
+    /// Set the value to `None` to reset the value to default (a fifth of the delay time)
+    pub fn set_reshow_time(&self, delay: Option<u16>) {
+        use winapi::um::commctrl::TTDT_RESHOW;
+        self.set_timing(TTDT_RESHOW, delay);
+    }
+
+    /// Return the delay before the tooltip of another control is displayed in milliseconds
+    pub fn reshow_time(&self) -> u16 {
+        use winapi::um::commctrl::TTDT_RESHOW;
+        self.timing(TTDT_RESHOW)
     }
 
     /// Enable or disable the control
@@ -373,6 +445,27 @@ impl Tooltip {
         wh::send_message(handle, TTM_DELTOOLW, 0, tool_ptr as LPARAM);   
     }
 
+    fn set_timing(&self, kind: WPARAM, value: Option<u16>) {
+        use winapi::um::commctrl::TTM_SETDELAYTIME;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        // A negative value resets the default timing
+        let value = match value {
+            Some(v) => v as LPARAM,
+            None => -1,
+        };
+
+        wh::send_message(handle, TTM_SETDELAYTIME, kind, value);
+    }
+
+    fn timing(&self, kind: WPARAM) -> u16 {
+        use winapi::um::commctrl::TTM_GETDELAYTIME;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TTM_GETDELAYTIME, kind, 0) as u16
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         winapi::um::commctrl::TOOLTIPS_CLASS
@@ -404,6 +497,11 @@ pub struct TooltipBuilder<'a> {
     default_ico: Option<TooltipIcon>,
     register: Vec<(ControlHandle, &'a str)>,
     register_cb: Vec<ControlHandle>,
+    balloon: bool,
+    max_width: Option<u32>,
+    delay_time: Option<u16>,
+    duration: Option<u16>,
+    reshow_time: Option<u16>,
 }
 
 impl<'a> TooltipBuilder<'a> {
@@ -430,15 +528,63 @@ impl<'a> TooltipBuilder<'a> {
         self
     }
 
+    pub fn balloon(mut self, balloon: bool) -> TooltipBuilder<'a> {
+        self.balloon = balloon;
+        self
+    }
+
+    pub fn max_width(mut self, width: Option<u32>) -> TooltipBuilder<'a> {
+        self.max_width = width;
+        self
+    }
+
+    pub fn delay_time(mut self, delay: Option<u16>) -> TooltipBuilder<'a> {
+        self.delay_time = delay;
+        self
+    }
+
+    pub fn duration(mut self, duration: Option<u16>) -> TooltipBuilder<'a> {
+        self.duration = duration;
+        self
+    }
+
+    pub fn reshow_time(mut self, delay: Option<u16>) -> TooltipBuilder<'a> {
+        self.reshow_time = delay;
+        self
+    }
+
     pub fn build(self, tooltip: &mut Tooltip) -> Result<(), NwgError> {
+        use winapi::um::commctrl::TTS_BALLOON;
+
         *tooltip = Default::default();
 
+        let mut flags = tooltip.flags();
+        if self.balloon {
+            flags |= TTS_BALLOON;
+        }
+
         tooltip.handle = ControlBase::build_hwnd()
             .class_name(tooltip.class_name())
             .forced_flags(tooltip.forced_flags())
-            .flags(tooltip.flags())
+            .flags(flags)
             .build()?;
 
+        if self.max_width.is_some() {
+            tooltip.set_max_width(self.max_width);
+        }
+
+        if self.delay_time.is_some() {
+            tooltip.set_delay_time(self.delay_time);
+        }
+
+        if self.duration.is_some() {
+            tooltip.set_duration(self.duration);
+        }
+
+        if self.reshow_time.is_some() {
+            tooltip.set_reshow_time(self.reshow_time);
+        }
+
         if self.title.is_some() || self.ico.is_some() || self.default_ico.is_some() {
             let title = self.title.unwrap_or("");
             match (self.ico, self.default_ico) {
@@ -36,6 +36,9 @@ bitflags! {
 A trackbar is a window that contains a slider (sometimes called a thumb) in a channel, and optional tick marks.
 When the user moves the slider, using either the mouse or the direction keys, the trackbar sends notification messages to indicate the change.
 
+The keyboard can only move the slider if the trackbar has the focus. Keep the `TAB_STOP` flag (set by default) so that the trackbar can be reached with the TAB key.
+Keyboard changes raise the same `OnVerticalScroll`/`OnHorizontalScroll` events as the mouse.
+
 Requires the `trackbar` feature.
 
 **Builder parameters:**
@@ -49,6 +52,8 @@ Requires the `trackbar` feature.
   * `selected_range`:   The selected value range of the trackbar. Used with `TrackBarFlags::RANGE`
   * `pos`:              The current value of the trackbar
   * `background_color`: The background color the of the trackbar
+  * `page_size`:        The number of logical positions the slider moves with the PAGE UP/PAGE DOWN keys
+  * `line_size`:        The number of logical positions the slider moves with the arrow keys
 
 
 **Control events:**
@@ -89,7 +94,9 @@ impl TrackBar {
             flags: None,
             ex_flags: 0,
             parent: None,
-            background_color: None
+            background_color: None,
+            page_size: None,
+            line_size: None,
         }
     }
 
@@ -183,6 +190,40 @@ impl TrackBar {
         wh::send_message(handle, TBM_GETTIC, index as WPARAM, 0) as usize
     }
 
+    /// Retrieves the number of logical positions the slider moves in response to the PAGE UP/PAGE DOWN keys
+    /// or when the user clicks in the trackbar's channel.
+    pub fn page_size(&self) -> usize {
+        use winapi::um::commctrl::TBM_GETPAGESIZE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TBM_GETPAGESIZE, 0, 0) as usize
+    }
+
+    /// Sets the number of logical positions the slider moves in response to the PAGE UP/PAGE DOWN keys
+    /// or when the user clicks in the trackbar's channel.
+    pub fn set_page_size(&self, size: usize) {
+        use winapi::um::commctrl::TBM_SETPAGESIZE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TBM_SETPAGESIZE, 0, size as LPARAM);
+    }
+
+    /// Retrieves the number of logical positions the slider moves in response to the arrow keys.
+    pub fn line_size(&self) -> usize {
+        use winapi::um::commctrl::TBM_GETLINESIZE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TBM_GETLINESIZE, 0, 0) as usize
+    }
+
+    /// Sets the number of logical positions the slider moves in response to the arrow keys.
+    pub fn set_line_size(&self, size: usize) {
+        use winapi::um::commctrl::TBM_SETLINESIZE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TBM_SETLINESIZE, 0, size as LPARAM);
+    }
+
     //
     // Basic methods
     //
@@ -322,6 +363,8 @@ pub struct TrackBarBuilder {
     ex_flags: u32,
     parent: Option<ControlHandle>,
     background_color: Option<[u8; 3]>,
+    page_size: Option<usize>,
+    line_size: Option<usize>,
 }
 
 impl TrackBarBuilder {
@@ -376,6 +419,16 @@ impl TrackBarBuilder {
         self
     }
 
+    pub fn page_size(mut self, size: Option<usize>) -> TrackBarBuilder {
+        self.page_size = size;
+        self
+    }
+
+    pub fn line_size(mut self, size: Option<usize>) -> TrackBarBuilder {
+        self.line_size = size;
+        self
+    }
+
     pub fn build(self, out: &mut TrackBar) -> Result<(), NwgError> {
         let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
 
@@ -400,6 +453,14 @@ impl TrackBarBuilder {
         if self.background_color.is_some() {
             out.hook_background_color(self.background_color.unwrap());
         }
+
+        if let Some(size) = self.page_size {
+            out.set_page_size(size);
+        }
+
+        if let Some(size) = self.line_size {
+            out.set_line_size(size);
+        }
     
         if self.focus {
             out.set_focus();
@@ -1,9 +1,10 @@
 use winapi::um::winuser::{WS_OVERLAPPEDWINDOW, WS_CLIPCHILDREN, WS_VISIBLE, WS_DISABLED, WS_MAXIMIZE, WS_MINIMIZE, WS_CAPTION,
-WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_POPUP, WS_EX_TOPMOST, WS_EX_ACCEPTFILES};
+WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_POPUP, WS_EX_TOPMOST, WS_EX_ACCEPTFILES, WS_EX_COMPOSITED, WS_EX_CONTEXTHELP,
+WS_EX_TOOLWINDOW};
 
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::check_hwnd;
-use crate::{NwgError, Icon};
+use crate::{NwgError, Icon, KeyModifiers};
 use super::{ControlBase, ControlHandle};
 
 const NOT_BOUND: &'static str = "Window is not yet bound to a winapi object";
@@ -27,6 +28,8 @@ bitflags! {
         * MINIMIZED: Create the window as minimized
         * RESIZABLE: Add a resizable border
         * VISIBLE: Show the window right away
+        * POPUP: A window without a title bar or borders. Use `Window::set_caption_area` and `Window::set_resize_border` to move and resize it.
+          See also the `frame` builder parameter.
     */
     pub struct WindowFlags: u32 {
         const MAIN_WINDOW = WS_CAPTION | WS_SYSMENU | WS_MINIMIZEBOX | WS_THICKFRAME | WS_MAXIMIZEBOX;
@@ -43,6 +46,19 @@ bitflags! {
     }
 }
 
+/// The frame of a window. See the `frame` builder parameter of `Window`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WindowFrame {
+    /// The frame described by the window flags
+    Standard,
+
+    /// A frame with a smaller title bar. The window is not displayed in the taskbar.
+    ToolWindow,
+
+    /// No title bar and no borders. Use `Window::set_caption_area` and `Window::set_resize_border` to move and resize the window.
+    Borderless,
+}
+
 
 /**
     A basic top level window. At least one top level window is required to make a NWG application.
@@ -59,6 +75,11 @@ bitflags! {
       * `icon`:        The window icon
       * `accept_file`: If the window should accept files by drag & drop
       * `topmost`:     If the window should always be on top of other system window
+      * `double_buffered`: If the window and its children should be painted with double buffering to reduce flickering
+      * `frame`:       The frame of the window. Defaults to `WindowFrame::Standard`. `Borderless` removes the title bar and the resizable border of the flags.
+      * `help_button`: If the title bar should have a `?` button. Clicking it, then a control, raises `OnHelp`. Not displayed if the window has a minimize or a maximize box.
+      * `caption_area`: The area of a borderless window that can be used to drag the window. See `set_caption_area`
+      * `resize_border`: The width of the resizable edges of a borderless window. See `set_resize_border`
       * `parent`:      Logical parent of the window, unlike children controls, this is NOT required.
 
     **Control events:**
@@ -69,6 +90,8 @@ bitflags! {
       * `OnPaint`: Generic on paint event
       * `OnKeyPress`: Generic key press
       * `OnKeyRelease`: Generic ket release
+      * `OnShortcut`: When a keyboard shortcut registered with `add_shortcut` is pressed
+      * `OnHelp`: When the user presses F1 or uses the help button on a control of the window
       * `OnResize`: When the window is resized
       * `OnResizeBegin`: Just before the window begins being resized by the user
       * `OnResizeEnd`: Just after the user stops resizing the window
@@ -93,6 +116,11 @@ impl Window {
             position: (300, 300),
             accept_files: false,
             topmost: false,
+            double_buffered: false,
+            help_button: false,
+            frame: WindowFrame::Standard,
+            caption_area: None,
+            resize_border: 0,
             flags: None,
             ex_flags: 0,
             icon: None,
@@ -108,6 +136,52 @@ impl Window {
         unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
     }
 
+    /// Return the frame of the window
+    pub fn frame(&self) -> WindowFrame {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let style = wh::get_style(handle);
+
+        if style & WS_POPUP == WS_POPUP && style & WS_CAPTION == 0 {
+            WindowFrame::Borderless
+        } else if wh::get_ex_style(handle) & WS_EX_TOOLWINDOW == WS_EX_TOOLWINDOW {
+            WindowFrame::ToolWindow
+        } else {
+            WindowFrame::Standard
+        }
+    }
+
+    /**
+        Return the control under the point `(x, y)`, in screen coordinates (see `GlobalCursor::position`).
+        Returns the window itself if the point is not over one of its children, or `None` if the point is outside the window.
+        Unlike `WindowFromPoint`, disabled controls and labels can be returned.
+    */
+    pub fn control_at(&self, x: i32, y: i32) -> Option<ControlHandle> {
+        use winapi::um::winuser::{WindowFromPoint, ChildWindowFromPointEx, ScreenToClient, IsChild, CWP_SKIPINVISIBLE, CWP_SKIPTRANSPARENT};
+        use winapi::shared::windef::POINT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            let mut hit = WindowFromPoint(POINT { x, y });
+            if hit != handle && IsChild(handle, hit) == 0 {
+                return None;
+            }
+
+            // Go down the hierarchy because `WindowFromPoint` skips disabled and transparent controls
+            loop {
+                let mut pt = POINT { x, y };
+                ScreenToClient(hit, &mut pt);
+                let child = ChildWindowFromPointEx(hit, pt, CWP_SKIPINVISIBLE | CWP_SKIPTRANSPARENT);
+                if child.is_null() || child == hit {
+                    break;
+                }
+                hit = child;
+            }
+
+            Some(ControlHandle::Hwnd(hit))
+        }
+    }
+
     /// Close the window as if the user clicked the X button.
     pub fn close(&self) {
         use winapi::um::winuser::WM_CLOSE;
@@ -116,6 +190,243 @@ impl Window {
         wh::post_message(handle, WM_CLOSE, 0, 0);
     }
 
+    /// Return `true` if the window can be resized by the user (`WS_THICKFRAME`)
+    pub fn resizable(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & WS_THICKFRAME == WS_THICKFRAME
+    }
+
+    /// Set if the window can be resized by the user. This adds or removes the resizable border and the maximize button.
+    /// The window can still be resized with `set_size`.
+    pub fn set_resizable(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let style = wh::get_style(handle);
+        match v {
+            true => wh::set_style(handle, style | WS_THICKFRAME | WS_MAXIMIZEBOX),
+            false => wh::set_style(handle, style & !(WS_THICKFRAME | WS_MAXIMIZEBOX))
+        }
+
+        unsafe { wh::refresh_frame(handle); }
+    }
+
+    /// Return `true` if the window stays on top of the other windows
+    pub fn topmost(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_ex_style(handle) & WS_EX_TOPMOST == WS_EX_TOPMOST
+    }
+
+    /// Set if the window stays on top of the other windows. Ex: a floating tool palette.
+    pub fn set_topmost(&self, v: bool) {
+        use winapi::um::winuser::{SetWindowPos, HWND_TOPMOST, HWND_NOTOPMOST, SWP_NOMOVE, SWP_NOSIZE, SWP_NOACTIVATE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let after = match v {
+            true => HWND_TOPMOST,
+            false => HWND_NOTOPMOST
+        };
+
+        unsafe { SetWindowPos(handle, after, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE); }
+    }
+
+    /// Return `true` if the close button of the window can be clicked. Return `false` if the window has no system menu.
+    pub fn close_button_enabled(&self) -> bool {
+        use winapi::um::winuser::{GetSystemMenu, GetMenuState, SC_CLOSE, MF_BYCOMMAND, MF_GRAYED, MF_DISABLED};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            let menu = GetSystemMenu(handle, 0);
+            if menu.is_null() {
+                return false;
+            }
+
+            let state = GetMenuState(menu, SC_CLOSE as u32, MF_BYCOMMAND);
+            state != u32::max_value() && state & (MF_GRAYED | MF_DISABLED) == 0
+        }
+    }
+
+    /**
+        Enable or disable the close button of the window. Ex: to prevent the user from closing the window during a critical operation.
+        This also disables the `Close` entry of the system menu and `Alt+F4`. The window can still be closed by the application.
+
+        Returns a `NwgError::NotSupported` if the window has no system menu (see `WindowFlags::SYS_MENU`).
+    */
+    pub fn set_close_button_enabled(&self, v: bool) -> Result<(), NwgError> {
+        use winapi::um::winuser::{GetSystemMenu, EnableMenuItem, SC_CLOSE, MF_BYCOMMAND, MF_ENABLED, MF_GRAYED};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            let menu = GetSystemMenu(handle, 0);
+            if menu.is_null() {
+                return Err(NwgError::not_supported("The window has no system menu"));
+            }
+
+            let state = match v {
+                true => MF_ENABLED,
+                false => MF_GRAYED
+            };
+
+            EnableMenuItem(menu, SC_CLOSE as u32, MF_BYCOMMAND | state);
+        }
+
+        Ok(())
+    }
+
+    /**
+        Register a keyboard shortcut on the window. When the user presses `modifiers` + `key` while the window is active,
+        an `OnShortcut` event with `id` is sent to the window, even if a child control (ex: a `TextInput`) has the focus.
+        `key` is a virtual key code from the `nwg::keys` module.
+
+        The key press is consumed and never reaches the focused control, so avoid combinations that children need
+        (ex: `CONTROL` + `C` in a text field). Registering the same combination again replaces its id.
+
+        ```rust
+        use native_windows_gui as nwg;
+        fn submit_shortcut(window: &nwg::Window) {
+            window.add_shortcut(nwg::KeyModifiers::CONTROL, nwg::keys::RETURN, 1);
+        }
+        ```
+    */
+    pub fn add_shortcut(&self, modifiers: KeyModifiers, key: u32, id: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::add_shortcut(handle, modifiers.bits(), key, id);
+    }
+
+    /// Unregister a keyboard shortcut added with `add_shortcut`
+    pub fn remove_shortcut(&self, modifiers: KeyModifiers, key: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::remove_shortcut(handle, modifiers.bits(), key);
+    }
+
+    /// Center the window in the work area (the screen without the taskbar) of the monitor under the mouse cursor.
+    pub fn center_on_screen(&self) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::center_window(handle, false); }
+    }
+
+    /// Center the window on its parent window. If the window has no parent, this is the same as `center_on_screen`.
+    /// The window is moved if needed to stay inside the work area of the parent monitor.
+    pub fn center_on_parent(&self) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::center_window(handle, true); }
+    }
+
+    /**
+        Draw the window with a 32 bits bitmap that has an alpha channel. Transparent pixels are not drawn and the mouse clicks
+        go through them, so the window can have any shape (ex: a splash screen). The window is resized to the size of the bitmap.
+
+        The color values of the bitmap must be **premultiplied** by their alpha value (`r * a / 255`, ...).
+        Pixels that are not premultiplied will look too bright on the edges of the shape.
+
+        Once this is called, the window is not painted by the system anymore: the title bar, the borders,
+        and the children controls are not visible. Use a window with the `POPUP` flag.
+
+        Returns an error if the bitmap is not a 32 bits bitmap or if the window cannot be updated.
+    */
+    pub fn set_layered_bitmap(&self, bitmap: &crate::Bitmap) -> Result<(), NwgError> {
+        use winapi::um::winuser::{UpdateLayeredWindow, GetDC, ReleaseDC, WS_EX_LAYERED, ULW_ALPHA};
+        use winapi::um::wingdi::{CreateCompatibleDC, DeleteDC, SelectObject, GetObjectW, BITMAP, BLENDFUNCTION, AC_SRC_OVER, AC_SRC_ALPHA};
+        use winapi::shared::windef::{POINT, SIZE, HGDIOBJ};
+        use std::{mem, ptr};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut info: BITMAP = unsafe { mem::zeroed() };
+        let info_size = mem::size_of::<BITMAP>() as i32;
+        if bitmap.handle.is_null() || unsafe { GetObjectW(bitmap.handle as HGDIOBJ, info_size, &mut info as *mut BITMAP as _) } == 0 {
+            return Err(NwgError::resource_create("Layered window bitmap is not a valid bitmap"));
+        }
+
+        if info.bmBitsPixel != 32 {
+            return Err(NwgError::not_supported("Layered window bitmaps must have 32 bits per pixel"));
+        }
+
+        let ex_style = wh::get_ex_style(handle);
+        if ex_style & WS_EX_LAYERED != WS_EX_LAYERED {
+            wh::set_ex_style(handle, ex_style | WS_EX_LAYERED);
+        }
+
+        let mut size = SIZE { cx: info.bmWidth, cy: info.bmHeight };
+        let mut source = POINT { x: 0, y: 0 };
+        let mut blend = BLENDFUNCTION {
+            BlendOp: AC_SRC_OVER,
+            BlendFlags: 0,
+            SourceConstantAlpha: 255,
+            AlphaFormat: AC_SRC_ALPHA,
+        };
+
+        unsafe {
+            let screen_dc = GetDC(ptr::null_mut());
+            let source_dc = CreateCompatibleDC(screen_dc);
+            let old = SelectObject(source_dc, bitmap.handle as HGDIOBJ);
+
+            let result = UpdateLayeredWindow(handle, screen_dc, ptr::null_mut(), &mut size, source_dc, &mut source, 0, &mut blend, ULW_ALPHA);
+            let error = match result {
+                0 => Some(NwgError::last_win32_error("UpdateLayeredWindow")),
+                _ => None
+            };
+
+            SelectObject(source_dc, old);
+            DeleteDC(source_dc);
+            ReleaseDC(ptr::null_mut(), screen_dc);
+
+            match error {
+                Some(e) => Err(e),
+                None => Ok(())
+            }
+        }
+    }
+
+    /// Return the area of the window that moves the window when dragged. See `set_caption_area`
+    pub fn caption_area(&self) -> Option<[i32; 4]> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_custom_frame(handle).0
+    }
+
+    /**
+        Set an area (`[x, y, width, height]`, in client coordinates) of the window that behaves like a title bar:
+        dragging it moves the window and double clicking it maximizes the window. `None` removes the area.
+
+        This is meant for borderless windows (the `POPUP` flag) that draw their own title bar. Controls over the area
+        (ex: custom close or minimize buttons) still receive the mouse events.
+    */
+    pub fn set_caption_area(&self, area: Option<[i32; 4]>) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (_, border) = wh::get_custom_frame(handle);
+        wh::set_custom_frame(handle, area, border);
+    }
+
+    /// Return the width of the resizable edges of the window. See `set_resize_border`
+    pub fn resize_border(&self) -> u32 {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_custom_frame(handle).1 as u32
+    }
+
+    /**
+        Let the user resize a borderless window (the `POPUP` flag) by dragging the `width` pixels next to the window edges.
+        `0` disables the resizing. The edges cannot be used while the window is maximized.
+    */
+    pub fn set_resize_border(&self, width: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (caption, _) = wh::get_custom_frame(handle);
+        wh::set_custom_frame(handle, caption, width as i32);
+    }
+
+    /// Return `true` if the keyboard can be used to move the focus between the window children (the default)
+    pub fn dialog_navigation(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_dialog_navigation(handle)
+    }
+
+    /**
+        Enable or disable the keyboard navigation in the window. When enabled (the default), Tab and Shift+Tab move the focus
+        between the children with the `TAB_STOP` flag and the arrows move the focus in a group of controls.
+        Disable it if the window handles the Tab and arrow keys in `OnKeyPress`.
+    */
+    pub fn set_dialog_navigation(&self, enabled: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::set_dialog_navigation(handle, enabled);
+    }
+
     /// Return the icon of the window
     pub fn icon(&self) -> Option<Icon> {
         use winapi::um::winuser::WM_GETICON;
@@ -245,6 +556,11 @@ pub struct WindowBuilder<'a> {
     position: (i32, i32),
     accept_files: bool,
     topmost: bool,
+    double_buffered: bool,
+    help_button: bool,
+    frame: WindowFrame,
+    caption_area: Option<[i32; 4]>,
+    resize_border: u32,
     flags: Option<WindowFlags>,
     ex_flags: u32,
     icon: Option<&'a Icon>,
@@ -293,17 +609,50 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    pub fn double_buffered(mut self, double_buffered: bool) ->  WindowBuilder<'a> {
+        self.double_buffered = double_buffered;
+        self
+    }
+
+    pub fn help_button(mut self, help_button: bool) ->  WindowBuilder<'a> {
+        self.help_button = help_button;
+        self
+    }
+
+    pub fn frame(mut self, frame: WindowFrame) -> WindowBuilder<'a> {
+        self.frame = frame;
+        self
+    }
+
+    pub fn caption_area(mut self, area: Option<[i32; 4]>) -> WindowBuilder<'a> {
+        self.caption_area = area;
+        self
+    }
+
+    pub fn resize_border(mut self, width: u32) -> WindowBuilder<'a> {
+        self.resize_border = width;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: Option<C>) -> WindowBuilder<'a> {
         self.parent = p.map(|p2| p2.into());
         self
     }
 
     pub fn build(self, out: &mut Window) -> Result<(), NwgError> {
-        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
 
         let mut ex_flags = self.ex_flags;
+        match self.frame {
+            WindowFrame::Standard => {},
+            WindowFrame::ToolWindow => { ex_flags |= WS_EX_TOOLWINDOW; },
+            WindowFrame::Borderless => { flags = (flags & !(WS_CAPTION | WS_THICKFRAME)) | WS_POPUP; }
+        }
+
         if self.topmost { ex_flags |= WS_EX_TOPMOST; }
         if self.accept_files { ex_flags |= WS_EX_ACCEPTFILES; }
+        if self.double_buffered { ex_flags |= WS_EX_COMPOSITED; }
+        if self.help_button { ex_flags |= WS_EX_CONTEXTHELP; }
 
         *out = Default::default();
 
@@ -322,6 +671,11 @@ impl<'a> WindowBuilder<'a> {
             out.set_icon(self.icon);
         }
 
+        if self.caption_area.is_some() || self.resize_border > 0 {
+            let handle = out.handle.hwnd().unwrap();
+            wh::set_custom_frame(handle, self.caption_area, self.resize_border as i32);
+        }
+
         Ok(())
     }
 
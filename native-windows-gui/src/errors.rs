@@ -26,6 +26,16 @@ pub enum NwgError {
     /// Error raised when an event handler could not be bound
     EventsBinding(String),
 
+    /// Error raised when an operation is not supported by a control
+    NotSupported(String),
+
+    /// Error raised when a winapi function failed. Holds the value of `GetLastError` and the name of the function.
+    ///
+    /// Returned by the `ControlHandle` setters (ex: `set_text`, `set_position_in`, `set_bounds_in`, `set_parent`).
+    /// The setters of the controls, `TextInput` included, keep their 1.0 signature and never return it.
+    /// To check if a change failed, call the setter of the control `handle` instead (ex: `input.handle.set_text("...")`).
+    Win32Error(u32, String),
+
     /// Error raised by the FileDialog object
     #[cfg(feature = "file-dialog")]
     FileDialogError(String),
@@ -65,6 +75,16 @@ impl NwgError {
         NwgError::EventsBinding(e.into())
     }
 
+    pub fn not_supported<S: Into<String>>(e: S) -> NwgError {
+        NwgError::NotSupported(e.into())
+    }
+
+    /// Build a `Win32Error` from the value of `GetLastError`. Must be called right after the failed winapi function.
+    pub fn last_win32_error<S: Into<String>>(function: S) -> NwgError {
+        let code = unsafe { winapi::um::errhandlingapi::GetLastError() };
+        NwgError::Win32Error(code, function.into())
+    }
+
     #[cfg(feature = "file-dialog")]
     pub fn file_dialog<S: Into<String>>(e: S) -> NwgError {
         NwgError::FileDialogError(e.into())
@@ -102,6 +122,8 @@ impl fmt::Display for NwgError {
             ResourceCreationError(reason) => write!(f, "Failed to create a resource: {:?}", reason),
             LayoutCreationError(reason) => write!(f, "Failed to create a layout: {:?}", reason),
             EventsBinding(reason) => write!(f, "Failed to bind events: {:?}", reason),
+            NotSupported(reason) => write!(f, "Operation not supported: {:?}", reason),
+            Win32Error(code, function) => write!(f, "{} failed with the error code {:#X}", function, code),
             
             #[cfg(feature = "file-dialog")]
             FileDialogError(reason) => write!(f, "File dialog actions failed: {:?}", reason),
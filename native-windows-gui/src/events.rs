@@ -10,6 +10,85 @@ pub enum MousePressEvent {
     MousePressRightDown
 }
 
+impl MousePressEvent {
+
+    /// The button that was pressed or released. Ex: `if evt.button() == MouseButton::Left { }`
+    pub fn button(&self) -> MouseButton {
+        match self {
+            MousePressEvent::MousePressLeftUp | MousePressEvent::MousePressLeftDown => MouseButton::Left,
+            MousePressEvent::MousePressRightUp | MousePressEvent::MousePressRightDown => MouseButton::Right,
+        }
+    }
+
+    /// `true` if the button was pressed, `false` if it was released
+    pub fn down(&self) -> bool {
+        match self {
+            MousePressEvent::MousePressLeftDown | MousePressEvent::MousePressRightDown => true,
+            MousePressEvent::MousePressLeftUp | MousePressEvent::MousePressRightUp => false,
+        }
+    }
+
+}
+
+/// A mouse button. See `MousePressEvent::button`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+}
+
+bitflags! {
+    /**
+        The keys and the mouse buttons that were held down when a mouse event was raised. See `EventData::OnMousePress`.
+        Use `bits()` to get the raw `MK_*` value sent by the system.
+
+        * SHIFT: The shift key
+        * CONTROL: The control key
+        * LEFT_BUTTON: The left mouse button
+        * RIGHT_BUTTON: The right mouse button
+        * MIDDLE_BUTTON: The middle mouse button
+    */
+    pub struct MouseModifiers: u32 {
+        const SHIFT = 0x0004;
+        const CONTROL = 0x0008;
+        const LEFT_BUTTON = 0x0001;
+        const RIGHT_BUTTON = 0x0002;
+        const MIDDLE_BUTTON = 0x0010;
+    }
+}
+
+bitflags! {
+    /**
+        The modifier keys of a keyboard shortcut. See `Window::add_shortcut` and `key_modifiers`.
+
+        * CONTROL: The control key
+        * SHIFT: The shift key
+        * ALT: The alt key
+    */
+    pub struct KeyModifiers: u32 {
+        const CONTROL = 0x01;
+        const SHIFT = 0x02;
+        const ALT = 0x04;
+    }
+}
+
+bitflags! {
+    /**
+        The kind of system changes merged in a `OnSystemColorsChanged` event. See `EventData::OnSystemColorsChanged`.
+
+        * COLORS: The system colors changed (`WM_SYSCOLORCHANGE`)
+        * THEME: The visual style was changed, enabled or disabled (`WM_THEMECHANGED`)
+        * COLOR_MODE: The light or dark mode of the applications changed (`WM_SETTINGCHANGE` with "ImmersiveColorSet")
+        * SETTINGS: Another system setting changed (`WM_SETTINGCHANGE`)
+    */
+    pub struct SystemChanges: u32 {
+        const COLORS = 0x01;
+        const THEME = 0x02;
+        const COLOR_MODE = 0x04;
+        const SETTINGS = 0x08;
+    }
+}
+
 /// Events are identifiers that are sent by controls on user interaction
 /// Some events also have data that can be further processed by the event loop. See `EventData`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -28,15 +107,34 @@ pub enum Event {
     /// Read the delta value with `EventData::OnMouseWheel` to check which key.
     OnMouseWheel,
 
-    /// Generic window event when the user right clicks a window
+    /// Generic window event when the user right clicks a window, or presses the context menu key (or SHIFT+F10).
+    /// Read the position where the popup menu should be displayed with `EventData::OnContextMenu`.
     OnContextMenu,
 
+    /// When the user presses F1 or clicks a control after clicking the `?` button of the title bar (see `WindowBuilder::help_button`).
+    /// The event is raised for the control that has the focus, or the control that was clicked.
+    /// Read the position of the mouse with `EventData::OnHelp`.
+    OnHelp,
+
+    /// Generic event when a window control receives the keyboard focus
+    OnFocus,
+
+    /// Generic event when a window control loses the keyboard focus
+    OnFocusLost,
+
+    /// When a control is enabled or disabled, by the application or by the system. Read the new state with `EventData::OnEnabledChanged`.
+    /// Only the control whose state changed receives the event. The children of a disabled window are not disabled themselves.
+    OnEnabledChanged,
+
     /// When a top level window control is created.
     OnInit,
 
     /// When a control needs to be redrawn
     OnPaint,
 
+    /// When an item of an owner drawn control needs to be painted. Read the item info with `EventData::OnDrawItem`
+    OnDrawItem,
+
     /// When a key is pressed on a keyboard. Unlike OnKeyDown, this returns a char (ex: 'c') in a EventData::OnChar.
     OnChar,
 
@@ -46,12 +144,22 @@ pub enum Event {
     /// When a key is pressed on a keyboard.Use EventData::OnKey to check which key.
     OnKeyRelease,
 
+    /// When a keyboard shortcut registered with `Window::add_shortcut` is pressed, even if a child control has the focus.
+    /// Read the shortcut id with `EventData::OnShortcut`. Only sent to the top level window.
+    OnShortcut,
+
+    /// When the system colors, the theme or the light/dark mode of the applications changes. Only sent to the top level windows.
+    /// The system sends many notifications in a row when the theme changes, they are merged in a single event.
+    /// Read what changed with `EventData::OnSystemColorsChanged`. Cached brushes and owner drawn controls should be refreshed.
+    OnSystemColorsChanged,
+
     /// Sent to a window when the size or position of the window is about to change. 
     /// An application can use the event data `EventData::OnMinMaxInfo` to override the minimum or maximum size.
     OnMinMaxInfo,
     
     /// When a control is resized by the user. 
     /// This is typically applied to top level windows but it also applies to children when layouts are used.
+    /// Read the new and the previous size of the client area with `EventData::OnResize`.
     OnResize,
 
     /// When a control is about to be resized by the user. 
@@ -70,6 +178,7 @@ pub enum Event {
 
     /// When a control is moved by the user. This is typically applied to top level windows.
     /// This is typically applied to top level windows but it also applies to children when layouts are used.
+    /// Read the new position of the client area with `EventData::OnMove`.
     OnMove,
 
     /// When a bar-like control value is changed.
@@ -81,7 +190,8 @@ pub enum Event {
     /// When a file is dropped into a control
     OnFileDrop,
 
-    /// When a button is clicked. Similar to a MouseUp event, but only for button control
+    /// When a button is clicked. Similar to a MouseUp event, but only for button control.
+    /// Check boxes and radio buttons send their new check state. See `EventData::on_button_click`
     OnButtonClick,
 
     /// When a button is clicked twice rapidly
@@ -102,6 +212,15 @@ pub enum Event {
     /// When TextInput value is changed
     OnTextInput,
 
+    /// When the value of a TextInput with a debounce delay stopped changing, or when it loses the focus with a pending change
+    OnTextInputDebounced,
+
+    /// When the selected text or the caret position of a RichTextBox changes
+    OnRichTextBoxSelectionChanged,
+
+    /// When a TextInput with an input mask loses the focus and its value does not match the mask
+    OnValidationFailed,
+
     /// When the list of a combobox is closed
     OnComboBoxClosed,
 
@@ -111,6 +230,12 @@ pub enum Event {
     /// When the current selection of the combobox was changed
     OnComboxBoxSelection,
 
+    /// When the user changed the text of an editable combobox
+    OnComboBoxTextChanged,
+
+    /// When the user pressed ENTER in an editable combobox
+    OnComboBoxSubmit,
+
     /// When the date select dropdown is expanded
     OnDatePickerDropdown,
 
@@ -126,6 +251,9 @@ pub enum Event {
     /// When an item on a list box is selected
     OnListBoxSelect,
 
+    /// When the user moved an item of a list box by dragging it. See `ListBox::set_drag_reorder`
+    OnListBoxItemMoved,
+
     /// The selected tab of a TabsContainer changed
     TabsContainerChanged,
 
@@ -237,6 +365,9 @@ pub enum Event {
     /// When a TrayNotification is closed due to a user click
     OnTrayNotificationUserClose,
 
+    /// When the user double clicks the system tray icon
+    OnTrayNotificationDoubleClick,
+
     /// When a timer delay is elapsed
     OnTimerTick,
 
@@ -271,9 +402,41 @@ pub enum EventData {
     /// The windows key code entered by a user. See the `nwg::keys` module
     OnKey(u32),
 
+    /// The id of the keyboard shortcut pressed by the user. See `Window::add_shortcut`
+    OnShortcut(u32),
+
+    /// The kind of changes merged in a `OnSystemColorsChanged` event
+    OnSystemColorsChanged(SystemChanges),
+
+    /// The new check state of a check box or a radio button clicked by the user with `OnButtonClick`.
+    /// Radio buttons are never `Indeterminate`. Buttons without a check state send `NoData`.
+    OnButtonClick(crate::CheckBoxState),
+
+    /// The screen position of the mouse when the user requested help with `OnHelp`
+    OnHelp([i32; 2]),
+
+    /// The screen position where a popup menu should be displayed for `OnContextMenu`.
+    /// This is the mouse position, or the center of the control if the menu was requested with the keyboard.
+    OnContextMenu([i32; 2]),
+
     /// Hold resources that will most likely be used during painting. 
     OnPaint(PaintData),
 
+    /// The item of an owner drawn control that must be painted
+    OnDrawItem(DrawItemData),
+
+    /// The new position of the upper left corner of the client area of a moved control
+    OnMove([i32; 2]),
+
+    /// The new and the previous size of the client area of a resized control
+    OnResize(ResizeData),
+
+    /// `true` if the control was enabled, `false` if it was disabled
+    OnEnabledChanged(bool),
+
+    /// The keys and the mouse buttons held down during a `OnMousePress` event. Not sent by the system tray.
+    OnMousePress(MouseModifiers),
+
     /// The delta value of a mouse wheel event. A positive value indicates that the wheel was rotated to the right; 
     /// a negative value indicates that the wheel was rotated to the left.
     OnMouseWheel(i32),
@@ -281,6 +444,12 @@ pub enum EventData {
     /// The path to one or more files that were dropped in the application
     OnFileDrop(DropFiles),
 
+    /// The text of the combobox when the user pressed ENTER with `OnComboBoxSubmit`
+    OnComboBoxSubmit(String),
+
+    /// The previous index and the new index of a list box item moved by the user
+    OnListBoxItemMoved { from: usize, to: usize },
+
     /// The handle to the item being deleted. The item is still valid.
     #[cfg(feature="tree-view")]
     OnTreeItemDelete(crate::TreeItem),
@@ -313,6 +482,14 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into a `&DrawItemData`. Panics if it's not the right type.
+    pub fn on_draw_item(&self) -> &DrawItemData {
+        match self {
+            EventData::OnDrawItem(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into a `&MinMaxInfo`. Panics if it's not the right type.
     pub fn on_min_max(&self) -> &MinMaxInfo {
         match self {
@@ -329,6 +506,38 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into the client area position for `OnMove`
+    pub fn on_move(&self) -> [i32; 2] {
+        match self {
+            EventData::OnMove(pos) => *pos,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the keys held down for `OnMousePress`
+    pub fn on_mouse_press(&self) -> MouseModifiers {
+        match self {
+            EventData::OnMousePress(modifiers) => *modifiers,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the sizes for `OnResize`
+    pub fn on_resize(&self) -> ResizeData {
+        match self {
+            EventData::OnResize(data) => *data,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the new state for `OnEnabledChanged`
+    pub fn on_enabled_changed(&self) -> bool {
+        match self {
+            EventData::OnEnabledChanged(enabled) => *enabled,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into a `&ToolTipTextData`. Panics if it's not the right type.
     pub fn on_tooltip_text(&self) -> &ToolTipTextData {
         match self {
@@ -353,6 +562,62 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into the menu position for `OnContextMenu`
+    pub fn on_context_menu(&self) -> [i32; 2] {
+        match self {
+            EventData::OnContextMenu(pos) => *pos,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the mouse position for `OnHelp`
+    pub fn on_help(&self) -> [i32; 2] {
+        match self {
+            EventData::OnHelp(pos) => *pos,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the new check state of a check box or a radio button for `OnButtonClick`
+    pub fn on_button_click(&self) -> crate::CheckBoxState {
+        match self {
+            EventData::OnButtonClick(state) => *state,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the shortcut id for `OnShortcut`
+    pub fn on_shortcut(&self) -> u32 {
+        match self {
+            EventData::OnShortcut(id) => *id,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the kind of changes for `OnSystemColorsChanged`
+    pub fn on_system_colors_changed(&self) -> SystemChanges {
+        match self {
+            EventData::OnSystemColorsChanged(changes) => *changes,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the text of the combobox for `OnComboBoxSubmit`
+    pub fn on_combo_box_submit(&self) -> &str {
+        match self {
+            EventData::OnComboBoxSubmit(text) => text,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the previous and the new index (from, to) of the item moved in a list box
+    pub fn on_list_box_item_moved(&self) -> (usize, usize) {
+        match self {
+            &EventData::OnListBoxItemMoved { from, to } => (from, to),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// unwraps event data into the removed tree item
     #[cfg(feature="tree-view")]
     pub fn on_tree_item_delete(&self) -> &crate::TreeItem {
@@ -405,9 +670,9 @@ impl EventData {
 //
 
 use winapi::um::commctrl::NMTTDISPINFOW;
-use winapi::um::winuser::{PAINTSTRUCT, MINMAXINFO, BeginPaint, EndPaint};
+use winapi::um::winuser::{PAINTSTRUCT, MINMAXINFO, DRAWITEMSTRUCT, BeginPaint, EndPaint};
 use winapi::um::shellapi::{HDROP, DragFinish};
-use winapi::shared::windef::{HWND, POINT};
+use winapi::shared::windef::{HWND, HDC, POINT, RECT};
 use std::fmt;
 
 /// A wrapper structure that sets the tooltip text on an `OnTooltipText` callback
@@ -493,6 +758,29 @@ impl fmt::Debug for WindowCloseData {
 }
 
 
+/// The client area size of a control before and after it was resized, in logical pixels
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResizeData {
+    /// The new size of the client area
+    pub size: [u32; 2],
+
+    /// The size of the client area before the resize
+    pub previous_size: [u32; 2],
+}
+
+impl ResizeData {
+
+    /// The change of size of the client area. Ex: `[-10, 0]` if the control lost 10 pixels of width.
+    pub fn delta(&self) -> [i32; 2] {
+        [
+            self.size[0] as i32 - self.previous_size[0] as i32,
+            self.size[1] as i32 - self.previous_size[1] as i32,
+        ]
+    }
+
+}
+
+
 /// Opaque type over a paint event's data
 #[derive(Debug)]
 pub struct PaintData {
@@ -520,6 +808,92 @@ impl PaintData {
 }
 
 
+/// Opaque type over the item of an owner drawn control that must be painted
+pub struct DrawItemData {
+    pub(crate) inner: *const DRAWITEMSTRUCT,
+}
+
+impl DrawItemData {
+
+    /// The index of the item to paint. `None` if the control is empty.
+    pub fn index(&self) -> Option<usize> {
+        let info = unsafe { &*self.inner };
+        match info.itemID as i32 {
+            -1 => None,
+            i => Some(i as usize)
+        }
+    }
+
+    /// The text of the item to paint. Only returns a value for combobox and listbox items.
+    pub fn text(&self) -> String {
+        use winapi::um::winuser::{ODT_COMBOBOX, ODT_LISTBOX, CB_GETLBTEXT, CB_GETLBTEXTLEN, LB_GETTEXT, LB_GETTEXTLEN};
+        use crate::win32::window_helper::send_message;
+        use crate::win32::base_helper::from_utf16;
+
+        let info = unsafe { &*self.inner };
+        let (len_msg, text_msg) = match info.CtlType {
+            ODT_COMBOBOX => (CB_GETLBTEXTLEN, CB_GETLBTEXT),
+            ODT_LISTBOX => (LB_GETTEXTLEN, LB_GETTEXT),
+            _ => { return String::new(); }
+        };
+
+        let index = match self.index() {
+            Some(i) => i,
+            None => { return String::new(); }
+        };
+
+        let length = send_message(info.hwndItem, len_msg, index, 0);
+        if length < 0 {
+            return String::new();
+        }
+
+        let mut buffer: Vec<u16> = vec![0; (length as usize) + 1];
+        send_message(info.hwndItem, text_msg, index, buffer.as_mut_ptr() as _);
+        from_utf16(&buffer)
+    }
+
+    /// If the item is selected
+    pub fn selected(&self) -> bool {
+        use winapi::um::winuser::ODS_SELECTED;
+        let info = unsafe { &*self.inner };
+        info.itemState & ODS_SELECTED == ODS_SELECTED
+    }
+
+    /// If the item has the keyboard focus
+    pub fn focused(&self) -> bool {
+        use winapi::um::winuser::ODS_FOCUS;
+        let info = unsafe { &*self.inner };
+        info.itemState & ODS_FOCUS == ODS_FOCUS
+    }
+
+    /// If the item is painted in the selection field of a combobox instead of the dropdown list
+    pub fn combobox_edit(&self) -> bool {
+        use winapi::um::winuser::ODS_COMBOBOXEDIT;
+        let info = unsafe { &*self.inner };
+        info.itemState & ODS_COMBOBOXEDIT == ODS_COMBOBOXEDIT
+    }
+
+    /// The device context to paint the item on
+    pub fn hdc(&self) -> HDC {
+        let info = unsafe { &*self.inner };
+        info.hDC
+    }
+
+    /// The boundaries of the item in the device context
+    pub fn rect(&self) -> RECT {
+        let info = unsafe { &*self.inner };
+        info.rcItem
+    }
+
+}
+
+impl fmt::Debug for DrawItemData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DrawItemData {{ index: {:?}, selected: {:?} }}", self.index(), self.selected())
+    }
+}
+
+
 /// Opaque type over one or more dragged files.
 pub struct DropFiles {
     pub(crate) drop: HDROP,
@@ -69,6 +69,12 @@ pub struct GridLayoutInner {
     /// The number of row. If None, compute the value from children.
     row_count: Option<u32>, 
 
+    /// The relative width of each column. Missing values default to 1.
+    column_weights: Vec<u32>,
+
+    /// The relative height of each row. Missing values default to 1.
+    row_weights: Vec<u32>,
+
     /// The spacing between controls
     spacing: u32
 }
@@ -84,6 +90,8 @@ A GridLayouts has the following properties:
 * max_size - The maximum size of the layout - (default: [u32::max_value(), u32::max_value()])
 * max_column - Number of columns - (default: None),
 * max_row - Number of rows - (default: None),
+* column_weights - The relative width of each column - (default: every column has a weight of 1),
+* row_weights - The relative height of each row - (default: every row has a weight of 1),
 
 ```rust
     use native_windows_gui as nwg;
@@ -93,6 +101,7 @@ A GridLayouts has the following properties:
             .max_row(Some(6))
             .spacing(5)
             .margin([0,0,0,0])
+            .column_weights(&[1, 2, 2])
             .child(0, 0, item1)
             .child_item(nwg::GridLayoutItem::new(item2, 1, 0, 2, 1))
             .build(&layout);
@@ -115,7 +124,9 @@ impl GridLayout {
             min_size: [0, 0],
             max_size: [u32::max_value(), u32::max_value()],
             column_count: None,
-            row_count: None
+            row_count: None,
+            column_weights: Vec::new(),
+            row_weights: Vec::new(),
         };
 
         GridLayoutBuilder { layout }
@@ -385,6 +396,20 @@ impl GridLayout {
         inner.row_count = count;
     }
 
+    /// Set the relative width of the columns. A column with a weight of 2 is twice as wide as a column with a weight of 1.
+    /// Columns without a value have a weight of 1.
+    pub fn column_weights(&self, weights: &[u32]) {
+        let mut inner = self.inner.borrow_mut();
+        inner.column_weights = weights.to_vec();
+    }
+
+    /// Set the relative height of the rows. A row with a weight of 2 is twice as tall as a row with a weight of 1.
+    /// Rows without a value have a weight of 1.
+    pub fn row_weights(&self, weights: &[u32]) {
+        let mut inner = self.inner.borrow_mut();
+        inner.row_weights = weights.to_vec();
+    }
+
     fn update_layout(&self, mut width: u32, mut height: u32) -> () {
         let inner = self.inner.borrow();
         if inner.base.is_null() || inner.children.len() == 0 {
@@ -430,26 +455,11 @@ impl GridLayout {
         width = width - ((sp * 2) * column_count);
         height = height - ((sp * 2) * row_count);
 
-        let item_width = width / column_count;
-        let item_height = height / row_count;
         let sp2 = sp * 2;
+        let columns = GridLayout::split_weighted(width, column_count, &inner.column_weights);
+        let rows = GridLayout::split_weighted(height, row_count, &inner.row_weights);
 
-        let mut columns = vec![item_width; column_count as usize];
-        let mut rows = vec![item_height; row_count as usize];
-        let extra_width = width - item_width * column_count;
-        if extra_width > 0 {
-            for x in &mut columns[0..(extra_width as usize)] {
-                *x += 1;
-            }
-        }
-        let extra_height = height - item_height * row_count;
-        if extra_height > 0 {
-            for x in &mut rows[0..(extra_height as usize)] {
-                *x += 1;
-            }
-        }
-
-        let mut last_handle = None;
+        let mut bounds = Vec::with_capacity(inner.children.len());
         for item in inner.children.iter() {
             let x = m_left + (sp + (sp2 * item.col)) + &columns[0..(item.col as usize)].iter().sum();
             let y = m_top + (sp + (sp2 * item.row)) + &rows[0..(item.row as usize)].iter().sum();
@@ -457,14 +467,38 @@ impl GridLayout {
             let local_width = &columns[(item.col as usize)..((item.col + item.col_span) as usize)].iter().sum() + (sp2 * (item.col_span - 1));
             let local_height = &rows[(item.row as usize)..((item.row + item.row_span) as usize)].iter().sum() + (sp2 * (item.row_span - 1));
 
-            unsafe {
-                wh::set_window_position(item.control, x as i32, y as i32);
-                wh::set_window_size(item.control, local_width, local_height, false);
-                wh::set_window_after(item.control, last_handle)
+            bounds.push((item.control, x as i32, y as i32, local_width, local_height));
+        }
+
+        let mut last_handle = None;
+        unsafe {
+            wh::set_windows_bounds(&bounds);
+
+            for item in inner.children.iter() {
+                wh::set_window_after(item.control, last_handle);
+                last_handle = Some(item.control);
             }
+        }
+    }
+
+    /// Split `size` into `count` cells using `weights`. The remaining pixels are given to the first cells.
+    fn split_weighted(size: u32, count: u32, weights: &[u32]) -> Vec<u32> {
+        let weights: Vec<u64> = (0..(count as usize))
+            .map(|i| weights.get(i).copied().unwrap_or(1) as u64)
+            .collect();
+
+        let total: u64 = weights.iter().sum();
+        if total == 0 {
+            return vec![0; count as usize];
+        }
 
-            last_handle = Some(item.control);
+        let mut cells: Vec<u32> = weights.iter().map(|&w| ((size as u64 * w) / total) as u32).collect();
+        let extra = (size - cells.iter().sum::<u32>()) as usize;
+        for (cell, _) in cells.iter_mut().zip(weights.iter()).filter(|(_, &w)| w > 0).take(extra) {
+            *cell += 1;
         }
+
+        cells
     }
 }
 
@@ -479,6 +513,8 @@ impl Default for GridLayout {
             max_size: [u32::max_value(), u32::max_value()],
             column_count: None,
             row_count: None,
+            column_weights: Vec::new(),
+            row_weights: Vec::new(),
             spacing: 5,
         };
 
@@ -562,6 +598,18 @@ impl GridLayoutBuilder {
         self
     }
 
+    /// Set the relative width of the columns. Columns without a value have a weight of 1.
+    pub fn column_weights(mut self, weights: &[u32]) -> GridLayoutBuilder {
+        self.layout.column_weights = weights.to_vec();
+        self
+    }
+
+    /// Set the relative height of the rows. Rows without a value have a weight of 1.
+    pub fn row_weights(mut self, weights: &[u32]) -> GridLayoutBuilder {
+        self.layout.row_weights = weights.to_vec();
+        self
+    }
+
     /// Build the layout object and bind the callback.
     /// Children must only contains window object otherwise this method will panic.
     pub fn build(self, layout: &GridLayout) -> Result<(), NwgError> {
@@ -25,12 +25,18 @@ pub use common_types::*;
 pub(crate) mod win32;
 pub use win32::{
  dispatch_thread_events, dispatch_thread_events_with_callback, stop_thread_dispatch, enable_visual_styles, init_common_controls, 
+ key_pressed, key_modifiers,
+ base_helper::escape_ampersand,
  window::{
      EventHandler, RawEventHandler,
-     full_bind_event_handler, bind_event_handler, unbind_event_handler,
-     bind_raw_event_handler, has_raw_handler, unbind_raw_event_handler
+     full_bind_event_handler, bind_event_handler, bind_checked_event_handler, unbind_event_handler,
+     bind_raw_event_handler, has_raw_handler, unbind_raw_event_handler, set_event_panic_handler,
+     with_events_suppressed
  },
- message_box::*
+ message_box::*,
+ input_box::{input_box, modal_input_box},
+ toast::toast,
+ form_values::{snapshot_values, restore_values}
 };
 
 pub(crate) use win32::window::bind_raw_event_handler_inner;
@@ -38,12 +44,17 @@ pub(crate) use win32::window::bind_raw_event_handler_inner;
 #[allow(deprecated)]
 pub use win32::high_dpi::{set_dpi_awareness, scale_factor, dpi};
 
+pub use win32::monitor::{Monitor, MonitorInfo};
+
 #[cfg(feature="cursor")]
 pub use win32::cursor::GlobalCursor;
 
 #[cfg(feature="clipboard")]
 pub use win32::clipboard::{Clipboard, ClipboardFormat, ClipboardData};
 
+#[cfg(feature="message-recorder")]
+pub use win32::message_recorder::{MessageRecorder, RecordedMessage};
+
 mod resources;
 pub use resources::*;
 
@@ -64,6 +64,9 @@ Represent a system font.
 Can be used with any controls that draws text. Due to the very limited way win32 can draw text,
 only family, size and weight can be configured.
 
+Fonts are set per control with the `set_font` method of the controls (or the `font` builder parameter).
+A single font can be shared by any number of controls: the controls never free the font they use, even when they are destroyed.
+
 Example:
 
 ```rust
@@ -185,25 +188,29 @@ impl Font {
         }
     }
 
-    /// Returns all the font families loaded on the OS. 
+    /// Returns all the font families loaded on the OS, sorted by name. Vertical fonts (with a name starting with `@`) are excluded.
     /// Probably pretty slow, so cache the value if possible
     pub fn families() -> Vec<String> {
+        Font::families_ex(false)
+    }
+
+    /// Returns all the font families loaded on the OS, sorted by name.
+    /// If `vertical` is true, the vertical fonts (with a name starting with `@`) used for east asian text are included.
+    /// Probably pretty slow, so cache the value if possible
+    pub fn families_ex(vertical: bool) -> Vec<String> {
         use winapi::um::wingdi::{LOGFONTW, TEXTMETRICW, DEFAULT_CHARSET, EnumFontFamiliesExW};
-        use winapi::um::winuser::GetDC;
+        use winapi::um::winuser::{GetDC, ReleaseDC};
         use winapi::shared::minwindef::{DWORD, LPARAM};
         use std::mem;
         
-        let mut families = Vec::with_capacity(16);
+        let mut families: Vec<String> = Vec::with_capacity(16);
 
         unsafe extern "system" fn callback(font_ptr: *const LOGFONTW, _txt: *const TEXTMETRICW, _font_type: DWORD, lparam: LPARAM) -> i32 {
             let families_ptr = lparam as *mut Vec<String>;
             let families = &mut *families_ptr;
 
             let font = &*font_ptr;
-            let family_text = from_utf16(&font.lfFaceName);
-            if !families.iter().any(|f| f == &family_text) {
-                families.push(family_text);
-            }
+            families.push(from_utf16(&font.lfFaceName));
 
             1
         }
@@ -214,8 +221,16 @@ impl Font {
             font.lfCharSet = DEFAULT_CHARSET as u8;
 
             EnumFontFamiliesExW(hdc, &mut font, Some(callback), (&mut families as *mut Vec<String>) as _, 0);
+            ReleaseDC(ptr::null_mut(), hdc);
+        }
+
+        if !vertical {
+            families.retain(|f| !f.starts_with('@'));
         }
 
+        // Fonts are enumerated once per character set
+        families.sort_by_key(|f| f.to_lowercase());
+        families.dedup();
         families.shrink_to_fit();
         families
     }
@@ -1,5 +1,5 @@
 use winapi::um::commctrl::{HIMAGELIST, ImageList_AddMasked};
-use winapi::shared::windef::{HICON, HBITMAP};
+use winapi::shared::windef::{HICON, HBITMAP, HDC};
 use crate::{Bitmap, Icon, NwgError};
 use std::ptr;
 
@@ -77,6 +77,19 @@ impl ImageList {
         unsafe { ImageList_GetImageCount(self.handle) as u32 }
     }
 
+    /**
+        Draw the image at `index` on a device context, with its top left corner at `(x, y)`.
+        Ex: to paint an icon in the `OnDrawItem` event of an owner drawn combobox, using `DrawItemData::hdc`.
+        Does nothing if the index is out of bound.
+    */
+    pub fn draw(&self, index: i32, hdc: HDC, x: i32, y: i32) {
+        use winapi::um::commctrl::{ImageList_Draw, ILD_TRANSPARENT};
+
+        if self.handle.is_null() { panic!(NOT_BOUND); }
+
+        unsafe { ImageList_Draw(self.handle, index, hdc, x, y, ILD_TRANSPARENT); }
+    }
+
     /// Adds a new bitmap to the image list. Returns the index to the image. Panics if the bitmap was not initialized
     pub fn add_bitmap(&self, bitmap: &Bitmap) -> i32 {
         if self.handle.is_null() { panic!(NOT_BOUND); }
@@ -626,6 +626,7 @@ mod partial_controls_test_ui {
                 .default_decoration(Some("More info"), Some(TooltipIcon::InfoLarge))
                 .register(&data.test_list_box1, "Simple list")
                 .register(&data.test_list_box2, "Multi select list")
+                .reshow_time(Some(200))
                 .build(&mut data.test_ttp3)?;
 
             //
@@ -896,16 +897,20 @@ mod partial_controls_test_ui {
                         color_select(self);
                     } else if &handle == &self.test_select_font_button {
                         font_select(self);
+                    } else if &handle == &self.test_checkbox1 {
+                        assert_eq!(_evt_data.on_button_click(), self.test_checkbox1.check_state());
+                    } else if &handle == &self.test_checkbox2 {
+                        assert_eq!(_evt_data.on_button_click(), self.test_checkbox2.check_state());
                     } else if &handle == &self.run_tray_test {
                         run_tray_tests(self);
                     },
                 E::OnContextMenu => 
                     if &handle == &self.window {
-                        show_pop_menu(self, evt);
+                        show_pop_menu(self, &_evt_data);
                     } else if &handle == &self.basics_control_tab {
-                        show_pop_menu(self, evt);
+                        show_pop_menu(self, &_evt_data);
                     } else if &handle == &self.tray_icon_2 {
-                        show_pop_menu(self, evt);
+                        show_pop_menu(self, &_evt_data);
                     },
                 E::OnTooltipText => 
                     if &handle == &self.window {
@@ -991,8 +996,8 @@ fn init_list_view(app: &ControlsTest) {
     }
 }
 
-fn show_pop_menu(app: &ControlsTest, _evt: Event) {
-    let (x, y) = GlobalCursor::position();
+fn show_pop_menu(app: &ControlsTest, evt_data: &EventData) {
+    let [x, y] = evt_data.on_context_menu();
     app.pop_menu.popup(x, y);
 }
 
@@ -1020,9 +1025,80 @@ fn run_window_tests(app: &ControlsTest, _evt: Event) {
         // The actual size return here might be less because it does not take account of the menubar
         // assert_eq!(app.window.size(), (500, 400));
 
+        // A window that is not resizable rejects the size requested in WM_SIZING
+        unsafe {
+            use winapi::um::winuser::{WM_SIZING, WMSZ_BOTTOMRIGHT, GetWindowRect};
+            use winapi::shared::windef::RECT;
+
+            let hwnd = app.window.handle.hwnd().unwrap();
+            let mut current: RECT = std::mem::zeroed();
+            GetWindowRect(hwnd, &mut current);
+
+            let sizing = || {
+                let mut rect = RECT { right: current.right + 50, bottom: current.bottom + 50, ..current };
+                app.window.handle.send_message(WM_SIZING, WMSZ_BOTTOMRIGHT as _, &mut rect as *mut RECT as _);
+                (rect.right - rect.left, rect.bottom - rect.top)
+            };
+
+            let size = (current.right - current.left, current.bottom - current.top);
+            assert_eq!(app.window.resizable(), true);
+            assert_eq!(sizing(), (size.0 + 50, size.1 + 50));
+
+            app.window.set_resizable(false);
+            assert_eq!(app.window.resizable(), false);
+            assert_eq!(sizing(), size);
+
+            app.window.set_resizable(true);
+            assert_eq!(sizing(), (size.0 + 50, size.1 + 50));
+        }
+
+        {
+            use crate::win32::resources_helper as rh;
+            use winapi::um::winuser::{GetIconInfo, ICONINFO};
+            use winapi::um::wingdi::DeleteObject;
+
+            let (mut bitmap, mut icon) = (None, None);
+
+            // GDI `GetObject` does not accept icons, `GetIconInfo` fails once an icon is destroyed
+            let resources_alive = || unsafe {
+                let mut info: ICONINFO = std::mem::zeroed();
+                let icon_alive = GetIconInfo(app.love_icon.handle as _, &mut info) != 0;
+                if icon_alive {
+                    DeleteObject(info.hbmColor as _);
+                    DeleteObject(info.hbmMask as _);
+                }
+
+                rh::is_bitmap(app.ferris.handle as _) && icon_alive
+            };
+
+            assert_eq!(app.test_img_frame.scaling(), ImageFrameScaling::Center);
+            app.test_img_frame.set_scaling(ImageFrameScaling::Stretch);
+            assert_eq!(app.test_img_frame.scaling(), ImageFrameScaling::Stretch);
+
+            // Swapping the images must not destroy the resources
+            app.test_img_frame.set_icon(Some(&app.love_icon));
+            app.test_img_frame.image(&mut bitmap, &mut icon);
+            assert!(bitmap.is_none() && icon.is_some());
+            assert!(resources_alive());
+
+            app.test_img_frame.set_bitmap(Some(&app.ferris));
+            app.test_img_frame.image(&mut bitmap, &mut icon);
+            assert!(bitmap.is_some() && icon.is_none());
+            assert!(resources_alive());
+
+            app.test_img_frame.set_bitmap(None);
+            assert!(resources_alive());
+
+            app.test_img_frame.set_bitmap(Some(&app.ferris));
+            app.test_img_frame.image(&mut bitmap, &mut icon);
+            assert!(bitmap.is_some());
+            assert!(resources_alive());
+        }
+
         app.runs.borrow_mut().window = true;
     } else {
         app.window.set_text("Controls");
+        app.test_img_frame.set_scaling(ImageFrameScaling::Center);
         app.runs.borrow_mut().window = false;
     }
 }
@@ -1042,6 +1118,16 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
         app.test_button.set_size(120, 35);
         assert_eq!(app.test_button.size(), (120, 35));
 
+        let (short_width, _) = app.test_button.ideal_size();
+        app.test_button.set_text("A much longer text for a simple button");
+        let (long_width, long_height) = app.test_button.ideal_size();
+        assert!(long_width > short_width && long_height > 0);
+        app.test_button.fit_to_content();
+        assert_eq!(app.test_button.size(), (long_width, long_height));
+        assert_eq!(app.test_button.position(), (5, 5));
+        app.test_button.set_text("New Text");
+        app.test_button.set_size(120, 35);
+
         if app.basics_control_tab.visible() {
             assert_eq!(app.test_button.visible(), true);
             app.test_button.set_visible(false);
@@ -1051,8 +1137,28 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_button.set_focus();
         assert_eq!(app.test_button.focus(), true);
+        assert_eq!(app.window.handle.focused_child(), Some(app.test_button.handle));
+        assert_eq!(app.basics_control_tab.handle.focused_child(), Some(app.test_button.handle));
+        assert_eq!(app.test_button.handle.focused_child(), Some(app.test_button.handle));
+        assert_eq!(app.test_date.handle.focused_child(), None);
         app.window.set_focus();
         assert_eq!(app.test_button.focus(), false);
+        assert_eq!(app.window.handle.focused_child(), Some(app.window.handle));
+
+        #[cfg(feature = "message-recorder")]
+        {
+            use winapi::um::winuser::{BM_CLICK, BN_CLICKED, WM_COMMAND};
+            use winapi::shared::minwindef::HIWORD;
+
+            let recorder = MessageRecorder::new(&app.basics_control_tab.handle).unwrap();
+            unsafe { app.test_button.handle.send_message(BM_CLICK, 0, 0); }
+
+            let button = app.test_button.handle.hwnd().unwrap() as isize;
+            assert!(recorder.messages().iter().any(|m| m.msg == WM_COMMAND && m.l == button && HIWORD(m.w as u32) == BN_CLICKED));
+
+            recorder.clear();
+            assert_eq!(recorder.contains(WM_COMMAND), false);
+        }
 
         assert_eq!(app.test_button.enabled(), true);
         app.test_button.set_enabled(false);
@@ -1101,9 +1207,27 @@ fn run_check_box_tests(app: &ControlsTest, _evt: Event) {
         app.test_checkbox1.set_check_state(CheckBoxState::Indeterminate);
         assert_eq!(app.test_checkbox1.check_state(), CheckBoxState::Indeterminate);
 
+        assert_eq!(app.test_button_checkbox.push_like(), true);
+        app.test_button_checkbox.set_check_state(CheckBoxState::Checked);
+        app.test_button_checkbox.set_push_like(false);
+        assert_eq!(app.test_button_checkbox.push_like(), false);
+        assert_eq!(app.test_button_checkbox.check_state(), CheckBoxState::Checked);
+        app.test_button_checkbox.set_push_like(true);
+        assert_eq!(app.test_button_checkbox.push_like(), true);
+        app.test_button_checkbox.set_push_like(false);
+        assert_eq!(app.test_button_checkbox.push_like(), false);
+
+        app.test_checkbox1.set_push_like(true);
+        app.test_checkbox1.set_tristate(true);
+        assert_eq!(app.test_checkbox1.push_like(), true);
+        app.test_checkbox1.set_push_like(false);
+        assert_eq!(app.test_checkbox1.tristate(), true);
+
         app.runs.borrow_mut().check = true;
     } else {
         app.test_checkbox1.set_tristate(false);
+        app.test_button_checkbox.set_push_like(true);
+        app.test_button_checkbox.set_check_state(CheckBoxState::Unchecked);
         app.runs.borrow_mut().check = false;
     }
 }
@@ -1203,6 +1327,28 @@ fn run_font_tests(app: &ControlsTest, _evt: Event) {
 
         assert_eq!(app.test_label.font().as_ref(), Some(&app.arial_font));
 
+        // Fonts are set per control. Controls sharing a font do not own it.
+        app.test_font_output.set_font(Some(&app.segoe_font));
+        assert_eq!(app.test_text_input.font().as_ref(), Some(&app.arial_font));
+        assert_eq!(app.test_font_output.font().as_ref(), Some(&app.segoe_font));
+        assert_eq!(app.test_text_box.font().as_ref(), Some(&app.arial_font));
+
+        // Narrower text wraps on more lines
+        let wide = app.test_text_box.handle.preferred_height(400);
+        let narrow = app.test_text_box.handle.preferred_height(50);
+        assert!(wide > 0 && narrow >= wide);
+
+        // Ampersands are measured like the label draws them. `&&` is a single ampersand when mnemonics are enabled.
+        let (mut doubled, mut single) = (Label::default(), Label::default());
+        Label::builder().text(&"&& ".repeat(40)).font(Some(&app.arial_font)).parent(&app.window).build(&mut doubled).unwrap();
+        Label::builder().text(&"& ".repeat(40)).font(Some(&app.arial_font)).parent(&app.window).build(&mut single).unwrap();
+
+        let literal = doubled.handle.preferred_height(100);
+        doubled.set_mnemonic(true);
+        let prefixed = doubled.handle.preferred_height(100);
+        assert!(prefixed < literal);
+        assert_eq!(prefixed, single.handle.preferred_height(100));
+
         app.runs.borrow_mut().font = true;
     } else {
         app.test_label.set_font(None);
@@ -1214,6 +1360,9 @@ fn run_font_tests(app: &ControlsTest, _evt: Event) {
         app.test_list_box1.set_font(None);
         app.test_list_box2.set_font(None);
         app.controls_holder.set_font(None);
+        app.test_text_input.set_font(None);
+        app.test_text_box.set_font(None);
+        app.test_font_output.set_font(None);
         app.test_tree.set_font(None);
 
         app.test_list_box1.set_size(130, 100);
@@ -1261,6 +1410,14 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_list_box1.remove(0);
 
+        app.test_list_box1.set_item_data(0, 42);
+        app.test_list_box1.move_item(0, 2);
+        assert_eq!(app.test_list_box1.collection()[..3], ["White", "Green", "BOO!"]);
+        assert_eq!(app.test_list_box1.item_data(2), Some(42));
+
+        app.test_list_box1.set_drag_reorder(true);
+        assert_eq!(app.test_list_box1.drag_reorder(), true);
+
         
         app.test_list_box2.multi_add_selection(0);
         app.test_list_box2.multi_add_selection(2);
@@ -1288,11 +1445,16 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
         app.test_list_box2.multi_unselect_range(0..1);
         assert_eq!(app.test_list_box2.multi_selection_len(), 1);
         assert_eq!(app.test_list_box2.multi_selection(), vec![2]);
+        assert_eq!(app.test_list_box2.multi_selection_strings(), vec!["Parrot".to_string()]);
+
+        app.test_list_box2.unselect_all();
+        assert_eq!(app.test_list_box2.multi_selection_strings(), Vec::<String>::new());
 
         app.runs.borrow_mut().list = true;
     } else {
         app.test_list_box2.unselect_all();
         app.test_list_box1.set_collection(vec!["Red", "White", "Green", "Yellow"]);
+        app.test_list_box1.set_drag_reorder(false);
 
         app.runs.borrow_mut().list = false;
     }
@@ -1331,6 +1493,38 @@ fn run_radio_tests(app: &ControlsTest, _evt: Event) {
         app.test_radio2.set_check_state(RadioButtonState::Unchecked);
         assert_eq!(app.test_radio2.check_state(), RadioButtonState::Unchecked);
 
+        // The arrow keys move the focus and the check state inside the group, wrapping around at the end of the group
+        if app.basics_control_tab.visible() {
+            use winapi::um::winuser::{IsDialogMessageW, MSG, WM_KEYDOWN, VK_DOWN, VK_UP};
+
+            let press = |key: i32| unsafe {
+                let mut msg: MSG = std::mem::zeroed();
+                msg.hwnd = app.window.handle.focused_child().and_then(|h| h.hwnd()).unwrap();
+                msg.message = WM_KEYDOWN;
+                msg.wParam = key as _;
+                IsDialogMessageW(app.window.handle.hwnd().unwrap(), &mut msg);
+            };
+
+            app.test_radio3.set_check_state(RadioButtonState::Checked);
+            app.test_radio1.set_check_state(RadioButtonState::Checked);
+            app.test_radio1.set_focus();
+
+            press(VK_DOWN);
+            assert_eq!(app.test_radio2.focus(), true);
+            assert_eq!(app.test_radio2.check_state(), RadioButtonState::Checked);
+            assert_eq!(app.test_radio1.check_state(), RadioButtonState::Unchecked);
+
+            press(VK_DOWN);
+            assert_eq!(app.test_radio1.focus(), true);
+            assert_eq!(app.test_radio1.check_state(), RadioButtonState::Checked);
+            assert_eq!(app.test_radio2.check_state(), RadioButtonState::Unchecked);
+
+            press(VK_UP);
+            assert_eq!(app.test_radio2.check_state(), RadioButtonState::Checked);
+            assert_eq!(app.test_radio3.check_state(), RadioButtonState::Checked);
+            assert_eq!(app.test_radio4.focus(), false);
+        }
+
         app.runs.borrow_mut().radio = true;
     } else {
         app.runs.borrow_mut().radio = false;
@@ -1342,9 +1536,192 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
         app.test_text_input.set_text("New Text");
         assert_eq!(&app.test_text_input.text(), "New Text");
 
+        assert_eq!(app.test_text_box.lines(), vec!["Multi", "Line", "Text"]);
+        app.test_text_box.set_text("First\r\n\r\nLast\r\n");
+        assert_eq!(app.test_text_box.lines(), vec!["First", "", "Last", ""]);
+        app.test_text_box.set_text("");
+        assert_eq!(app.test_text_box.lines(), vec![""]);
+        app.test_text_box.set_text("Multi\r\nLine\r\nText");
+
+        {
+            let text = "A line long enough to be wrapped many times in a small text box";
+            let mut wrapped = TextBox::default();
+            TextBox::builder()
+                .text(text)
+                .size((80, 100))
+                .word_wrap(true)
+                .parent(&app.window)
+                .build(&mut wrapped)
+                .unwrap();
+
+            assert_eq!(app.test_text_box.word_wrap(), false);
+            assert_eq!(wrapped.word_wrap(), true);
+            assert!(wrapped.lines().len() > 1);
+        }
+
+        // Toggling the word wrap recreates the control and keeps its state
+        {
+            let text = "A line long enough to be wrapped many times in a small text box";
+            let mut toggled = TextBox::default();
+            TextBox::builder()
+                .text(text)
+                .size((80, 100))
+                .position((10, 10))
+                .limit(100)
+                .font(Some(&app.arial_font))
+                .parent(&app.window)
+                .build(&mut toggled)
+                .unwrap();
+
+            assert_eq!(toggled.word_wrap(), false);
+            assert_eq!(toggled.lines().len(), 1);
+            toggled.set_selection(2..6);
+            toggled.set_modified(true);
+
+            let old_handle = toggled.handle;
+            toggled.set_word_wrap(true).unwrap();
+            assert!(toggled.handle != old_handle);
+            assert_eq!(toggled.word_wrap(), true);
+            assert!(toggled.lines().len() > 1);
+            assert_eq!(toggled.text(), text);
+            assert_eq!(toggled.selection(), 2..6);
+            assert_eq!(toggled.limit(), 100);
+            assert_eq!(toggled.modified(), true);
+            assert_eq!(toggled.size(), (80, 100));
+            assert_eq!(toggled.position(), (10, 10));
+            assert_eq!(toggled.font().as_ref(), Some(&app.arial_font));
+
+            toggled.set_word_wrap(false).unwrap();
+            assert_eq!(toggled.word_wrap(), false);
+            assert_eq!(toggled.lines().len(), 1);
+            assert_eq!(toggled.text(), text);
+            assert_eq!(toggled.selection(), 2..6);
+            assert_eq!(toggled.modified(), true);
+        }
+
         app.test_text_input.set_limit(32);
         assert_eq!(app.test_text_input.limit(), 32);
 
+        // Incomplete decimals can be typed, but are not complete values. The wheel still reads them.
+        {
+            use winapi::um::winuser::{WM_MOUSEWHEEL, WHEEL_DELTA};
+
+            let decimal = InputMask::Decimal;
+            assert!(decimal.accepts_partial("1.") && decimal.accepts_partial("-."));
+            assert!(!decimal.accepts("1.") && !decimal.accepts("-.") && !decimal.accepts("-"));
+            assert!(decimal.accepts("-1.5") && decimal.accepts("2"));
+
+            app.test_text_input.set_mask(Some(InputMask::Decimal));
+            app.test_text_input.set_wheel_step(Some(1.0));
+            app.test_text_input.set_text("1.");
+            unsafe { app.test_text_input.handle.send_message(WM_MOUSEWHEEL, ((WHEEL_DELTA as u32) << 16) as _, 0); }
+            assert_eq!(&app.test_text_input.text(), "2");
+
+            app.test_text_input.set_text("-.");
+            unsafe { app.test_text_input.handle.send_message(WM_MOUSEWHEEL, ((WHEEL_DELTA as u32) << 16) as _, 0); }
+            assert_eq!(&app.test_text_input.text(), "1");
+
+            // The wheel stops at the largest value allowed by the mask
+            app.test_text_input.set_mask(Some(InputMask::Digits(2)));
+            app.test_text_input.set_wheel_step(Some(5.0));
+            app.test_text_input.set_text("97");
+            unsafe { app.test_text_input.handle.send_message(WM_MOUSEWHEEL, ((WHEEL_DELTA as u32) << 16) as _, 0); }
+            assert_eq!(&app.test_text_input.text(), "99");
+
+            // Without a mask, the wheel does not touch the text
+            assert_eq!(has_raw_handler(&app.test_text_input.handle, 2), true);
+            app.test_text_input.set_mask(None);
+            assert_eq!(has_raw_handler(&app.test_text_input.handle, 2), false);
+            app.test_text_input.set_text("1.5");
+            unsafe { app.test_text_input.handle.send_message(WM_MOUSEWHEEL, ((WHEEL_DELTA as u32) << 16) as _, 0); }
+            assert_eq!(&app.test_text_input.text(), "1.5");
+
+            app.test_text_input.set_wheel_step(None);
+            app.test_text_input.set_text("New Text");
+        }
+
+        // The debounce hooks are only installed while a delay is set
+        assert_eq!(app.test_text_input.debounce(), None);
+        assert_eq!(has_raw_handler(&app.test_text_input.handle, 3), false);
+        app.test_text_input.set_debounce(Some(300));
+        assert_eq!(app.test_text_input.debounce(), Some(300));
+        assert_eq!(has_raw_handler(&app.test_text_input.handle, 3), true);
+        app.test_text_input.set_text("New Text");
+        app.test_text_input.set_debounce(None);
+        assert_eq!(app.test_text_input.debounce(), None);
+        assert_eq!(has_raw_handler(&app.test_text_input.handle, 3), false);
+
+        // A burst of changes raises a single OnTextInputDebounced once the delay expired
+        {
+            use std::{rc::Rc, cell::Cell, time::{Duration, Instant}};
+            use winapi::um::winuser::{PeekMessageW, TranslateMessage, DispatchMessageW, MSG, PM_REMOVE};
+
+            let mut input = TextInput::default();
+            TextInput::builder().debounce(Some(50)).parent(&app.window).build(&mut input).unwrap();
+
+            let debounced = Rc::new(Cell::new(0));
+            let debounced_handler = debounced.clone();
+            let handler = bind_event_handler(&input.handle, &app.window.handle, move |evt, _evt_data, _handle| {
+                if evt == Event::OnTextInputDebounced {
+                    debounced_handler.set(debounced_handler.get() + 1);
+                }
+            });
+
+            for text in &["F", "Fe", "Fer", "Ferris"] {
+                input.set_text(text);
+            }
+            assert_eq!(debounced.get(), 0);
+
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_millis(300) {
+                unsafe {
+                    let mut msg: MSG = std::mem::zeroed();
+                    while PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                        TranslateMessage(&msg);
+                        DispatchMessageW(&msg);
+                    }
+                }
+
+                std::thread::sleep(Duration::from_millis(10));
+            }
+
+            assert_eq!(debounced.get(), 1);
+            unbind_event_handler(&handler);
+        }
+
+        {
+            use winapi::um::winuser::{WM_KEYDOWN, VK_UP, VK_DOWN};
+
+            let size = app.test_text_input.size();
+            app.test_text_input.set_text("5");
+            app.test_text_input.attach_spinner((0.0, 10.0), 2.0).unwrap();
+            assert_eq!(app.test_text_input.spinner(), true);
+            assert!(app.test_text_input.size().0 < size.0);
+
+            // The arrow keys of the buddy are handled by the up-down control
+            unsafe {
+                app.test_text_input.handle.send_message(WM_KEYDOWN, VK_UP as _, 0);
+                assert_eq!(&app.test_text_input.text(), "7");
+                for _ in 0..3 { app.test_text_input.handle.send_message(WM_KEYDOWN, VK_UP as _, 0); }
+                assert_eq!(&app.test_text_input.text(), "10");
+                app.test_text_input.handle.send_message(WM_KEYDOWN, VK_DOWN as _, 0);
+                assert_eq!(&app.test_text_input.text(), "8");
+            }
+
+            app.test_text_input.detach_spinner();
+            assert_eq!(app.test_text_input.spinner(), false);
+            assert_eq!(app.test_text_input.size(), size);
+            app.test_text_input.set_text("New Text");
+        }
+
+        app.test_text_input.handle.highlight([255, 0, 0], 60000);
+        app.test_text_input.handle.highlight([255, 100, 100], 60000);
+        assert_eq!(app.test_text_input.handle.clear_highlight(), true);
+        assert_eq!(app.test_text_input.handle.clear_highlight(), false);
+
+        // Visible for a short time when the test is run
+        app.test_text_input.handle.highlight([255, 100, 100], 1000);
+
         assert_eq!(app.test_text_input.password_char(), None);
         app.test_text_input.set_password_char(Some('X'));
         assert_eq!(app.test_text_input.password_char(), Some('X'));
@@ -1400,7 +1777,7 @@ fn run_progress_tests(app: &ControlsTest, _evt: Event) {
         app.test_progress1.set_step(100);
         assert!(app.test_progress1.step() == 100);
 
-        app.test_progress1.set_state(ProgressBarState::Paused);
+        app.test_progress1.set_state(ProgressBarState::Paused).unwrap();
         assert!(app.test_progress1.state() == ProgressBarState::Paused);
 
         app.test_progress1.advance();
@@ -1409,10 +1786,16 @@ fn run_progress_tests(app: &ControlsTest, _evt: Event) {
         app.test_progress1.advance_delta(50);
         assert!(app.test_progress1.pos() == 650);
 
+        // A marquee bar does not support states
+        match app.test_progress2.set_state(ProgressBarState::Error) {
+            Err(NwgError::NotSupported(_)) => {},
+            r => panic!("Unexpected result: {:?}", r)
+        }
+
         app.runs.borrow_mut().progress = true;
     } else {
         app.test_progress1.set_pos(0);
-        app.test_progress1.set_state(ProgressBarState::Normal);
+        app.test_progress1.set_state(ProgressBarState::Normal).unwrap();
         app.runs.borrow_mut().progress = false;
     }
 }
@@ -1444,10 +1827,20 @@ fn run_tooltip_tests(app: &ControlsTest, _evt: Event) {
     if !app.runs.borrow().tooltip {
 
         app.test_ttp2.set_enabled(false);
+        assert_eq!(app.test_ttp3.reshow_time(), 200);
 
         app.test_ttp1.set_delay_time(Some(100));
         assert_eq!(app.test_ttp1.delay_time(), 100);
 
+        app.test_ttp1.set_duration(Some(20000));
+        assert_eq!(app.test_ttp1.duration(), 20000);
+        app.test_ttp1.set_reshow_time(Some(50));
+        assert_eq!(app.test_ttp1.reshow_time(), 50);
+
+        app.test_ttp2.set_duration(Some(1000));
+        app.test_ttp2.set_duration(None);
+        assert_eq!(app.test_ttp2.duration(), app.test_ttp2.delay_time() * 10);
+
         app.test_ttp1.register(&app.test_checkbox1, "A simple checkbox");
         app.test_ttp1.register(&app.test_checkbox2, "A checkbox with 3 states!");
 
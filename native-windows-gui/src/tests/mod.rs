@@ -177,6 +177,21 @@ fn show(app: &TestControlPanel) {
     Clipboard::set_data_text(&app.window, text);
     assert!(Some(text) == Clipboard::data_text(&app.window).as_ref().map(|s| s as &str));
 
+    // The whole text is copied, even without a selection
+    let title = app.window.text();
+    Clipboard::copy_control_text(&app.window).unwrap();
+    assert_eq!(Clipboard::data_text(&app.window), Some(title));
+
+    Clipboard::set_text("Exported text").unwrap();
+    assert_eq!(Clipboard::text(), Some("Exported text".to_string()));
+    Clipboard::set_text("").unwrap();
+    assert_eq!(Clipboard::text(), Some(String::new()));
+
+    Clipboard::open(&app.window);
+    Clipboard::empty();
+    Clipboard::close();
+    assert_eq!(Clipboard::text(), None);
+
     app.window.set_visible(true);
 }
 
@@ -6,6 +6,8 @@ pub struct OtherTests {
     window: Window,
     layout: FlexboxLayout,
     test: Button,
+    tab_first: Button,
+    tab_second: Button,
 }
 
 mod other_tests_ui {
@@ -35,6 +37,16 @@ mod other_tests_ui {
                 .focus(true)
                 .parent(&data.window)
                 .build(&mut data.test)?;
+
+            Button::builder()
+                .text("Tab 1")
+                .parent(&data.window)
+                .build(&mut data.tab_first)?;
+
+            Button::builder()
+                .text("Tab 2")
+                .parent(&data.window)
+                .build(&mut data.tab_second)?;
             
             let ui = OtherTestsUi { inner: Rc::new(data), default_handler: Default::default() };
 
@@ -62,6 +74,8 @@ mod other_tests_ui {
                 .parent(&ui.window)
                 .flex_direction(stretch::style::FlexDirection::Column)
                 .child(&ui.test)
+                .child(&ui.tab_first)
+                .child(&ui.tab_second)
                 .build(&ui.layout)?;
             
             Ok(ui)
@@ -99,5 +113,814 @@ fn test_stuff(_t: &OtherTests) {
 fn other_tests() {
     init().expect("Failed to init Native Windows GUI");
     let _app = OtherTests::build_ui(Default::default()).expect("Failed to build UI");
+
+    // Text must survive a round trip, including surrogate pairs at the end of the text
+    let text = "日本語のテキスト 🦀👍";
+    _app.test.set_text(text);
+    assert_eq!(_app.test.text(), text);
+
+    // Tab navigation must follow the tab order instead of the creation order
+    _app.window.handle.set_tab_order(&[_app.tab_second.handle, _app.tab_first.handle, _app.test.handle]).unwrap();
+    unsafe {
+        use winapi::um::winuser::GetNextDlgTabItem;
+
+        let window = _app.window.handle.hwnd().unwrap();
+        let mut focus = _app.tab_second.handle.hwnd().unwrap();
+        let mut visited = Vec::new();
+        for _ in 0..3 {
+            focus = GetNextDlgTabItem(window, focus, 0);
+            visited.push(focus);
+        }
+
+        let expected = [&_app.tab_first, &_app.test, &_app.tab_second];
+        assert_eq!(visited, expected.iter().map(|b| b.handle.hwnd().unwrap()).collect::<Vec<_>>());
+    }
+
+    // Bounds are set and read in a single call
+    {
+        let button = &_app.tab_first.handle;
+        button.set_bounds_in(Coords::Parent, 10, 20, 100, 30).unwrap();
+        assert_eq!(button.bounds_in(Coords::Parent), (10, 20, 100, 30));
+        assert_eq!(_app.tab_first.size(), (100, 30));
+
+        let (x, y) = button.position_in(Coords::Screen);
+        assert_eq!(button.bounds_in(Coords::Screen), (x, y, 100, 30));
+
+        button.set_bounds_in(Coords::Screen, x + 5, y + 5, 80, 25).unwrap();
+        assert_eq!(button.bounds_in(Coords::Parent), (15, 25, 80, 25));
+    }
+
+    // The handle setters report the winapi failures
+    {
+        let mut button = Button::default();
+        Button::builder().text("Destroyed").parent(&_app.window).build(&mut button).unwrap();
+
+        let handle = button.handle;
+        assert!(handle.set_text("Still alive").is_ok());
+        drop(button);
+
+        match handle.set_text("Destroyed") {
+            Err(NwgError::Win32Error(1400, _)) => {},  // ERROR_INVALID_WINDOW_HANDLE
+            r => panic!("Unexpected result: {:?}", r)
+        }
+    }
+
+    // The redraw is enabled again if the closure panics. Disabling the redraw clears the visible style.
+    {
+        use winapi::um::winuser::WS_VISIBLE;
+        use crate::win32::window_helper as wh;
+
+        let handle = _app.tab_first.handle.hwnd().unwrap();
+        let result = std::panic::catch_unwind(|| {
+            wh::without_redraw(handle, || {
+                assert_eq!(wh::get_style(handle) & WS_VISIBLE, 0);
+                panic!("Redraw test");
+            })
+        });
+
+        assert!(result.is_err());
+        assert_eq!(wh::get_style(handle) & WS_VISIBLE, WS_VISIBLE);
+    }
+
+    // Appended text goes at the end of a rich text box, wherever the caret is
+    #[cfg(feature = "rich-textbox")]
+    {
+        let mut rich = RichTextBox::default();
+        RichTextBox::builder().text("Hello").parent(&_app.window).build(&mut rich).unwrap();
+
+        rich.set_selection(0..0);
+        rich.append_text(" World", &CharFormat { text_color: Some([200, 0, 0]), ..Default::default() });
+        assert!(rich.text().ends_with(" World"));
+        assert_eq!(rich.text(), "Hello World");
+        assert_eq!(rich.selection(), 0..0);
+    }
+
+    // The input box uses the default and the cancel buttons of the dialog navigation.
+    // A timer types in the dialog while `input_box` runs its modal loop.
+    {
+        use std::{ptr, cell::Cell};
+        use winapi::um::winuser::{SetTimer, KillTimer, FindWindowW, FindWindowExW, SetFocus, PostMessageW, SetWindowTextW, WM_KEYDOWN, VK_RETURN, VK_ESCAPE};
+        use winapi::shared::windef::HWND;
+        use crate::win32::base_helper::to_utf16;
+
+        thread_local! {
+            /// The key pressed in the dialog, and if the Cancel button has the focus when it is pressed
+            static INPUT_BOX_KEY: Cell<(i32, bool)> = Cell::new((0, false));
+        }
+
+        unsafe extern "system" fn drive_input_box(_hwnd: HWND, _msg: u32, id: usize, _time: u32) {
+            let dialog = FindWindowW(to_utf16("NativeWindowsGuiWindow").as_ptr(), to_utf16("Input box test").as_ptr());
+            if dialog.is_null() {
+                return;
+            }
+
+            KillTimer(ptr::null_mut(), id);
+
+            let (key, focus_cancel) = INPUT_BOX_KEY.with(|k| k.get());
+            let edit = FindWindowExW(dialog, ptr::null_mut(), to_utf16("Edit").as_ptr(), ptr::null());
+            let cancel = FindWindowExW(dialog, ptr::null_mut(), to_utf16("Button").as_ptr(), to_utf16("Cancel").as_ptr());
+            SetWindowTextW(edit, to_utf16("Ferris").as_ptr());
+
+            let target = match focus_cancel {
+                true => { SetFocus(cancel); cancel },
+                false => edit
+            };
+
+            PostMessageW(target, WM_KEYDOWN, key as _, 0);
+        }
+
+        let run = |key: i32, focus_cancel: bool| unsafe {
+            INPUT_BOX_KEY.with(|k| k.set((key, focus_cancel)));
+            SetTimer(ptr::null_mut(), 0, 10, Some(drive_input_box));
+            modal_input_box(&_app.window, "Input box test", "Name", "")
+        };
+
+        assert_eq!(run(VK_RETURN, false), Some("Ferris".to_string()));
+        assert_eq!(run(VK_ESCAPE, false), None);
+        assert_eq!(run(VK_RETURN, true), None);
+    }
+
+    // The focus rectangle of a canvas is only visible when the canvas has the focus
+    #[cfg(feature = "extern-canvas")]
+    {
+        let mut canvas = ExternCanvas::default();
+        ExternCanvas::builder()
+            .size((50, 50))
+            .position((0, 0))
+            .parent(Some(&_app.window))
+            .build(&mut canvas)
+            .unwrap();
+
+        _app.test.set_focus();
+        canvas.set_show_focus_rect(Some(true));
+        assert_eq!(canvas.focus_rect_visible(), false);
+
+        canvas.set_focus();
+        assert_eq!(canvas.focus_rect_visible(), true);
+        canvas.set_show_focus_rect(Some(false));
+        assert_eq!(canvas.focus_rect_visible(), false);
+
+        canvas.set_show_focus_rect(None);
+        _app.test.set_focus();
+        assert_eq!(canvas.focus_rect_visible(), false);
+    }
+
+    // System changes sent in a row are merged in a single event
+    {
+        use std::{rc::Rc, cell::RefCell, time::{Duration, Instant}};
+        use winapi::um::winuser::{PeekMessageW, TranslateMessage, DispatchMessageW, MSG, PM_REMOVE, WM_SYSCOLORCHANGE, WM_SETTINGCHANGE};
+        use crate::win32::base_helper::to_utf16;
+
+        let changes: Rc<RefCell<Vec<SystemChanges>>> = Rc::new(RefCell::new(Vec::new()));
+        let changes_handler = changes.clone();
+        let handler = full_bind_event_handler(&_app.window.handle, move |evt, evt_data, _handle| {
+            if evt == Event::OnSystemColorsChanged {
+                changes_handler.borrow_mut().push(evt_data.on_system_colors_changed());
+            }
+        });
+
+        let color_set = to_utf16("ImmersiveColorSet");
+        unsafe {
+            _app.window.handle.send_message(WM_SYSCOLORCHANGE, 0, 0);
+            _app.window.handle.send_message(WM_SETTINGCHANGE, 0, color_set.as_ptr() as _);
+        }
+        assert!(changes.borrow().is_empty());
+
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(500) {
+            unsafe {
+                let mut msg: MSG = std::mem::zeroed();
+                while PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                    TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            }
+
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(&*changes.borrow(), &[SystemChanges::COLORS | SystemChanges::COLOR_MODE]);
+        unbind_event_handler(&handler);
+    }
+
+    // Each window frame maps to its styles
+    {
+        use winapi::um::winuser::{WS_CAPTION, WS_THICKFRAME, WS_POPUP, WS_EX_TOOLWINDOW};
+        use crate::win32::window_helper as wh;
+
+        for &frame in &[WindowFrame::Standard, WindowFrame::ToolWindow, WindowFrame::Borderless] {
+            let mut window = Window::default();
+            Window::builder()
+                .flags(WindowFlags::MAIN_WINDOW)
+                .frame(frame)
+                .build(&mut window)
+                .unwrap();
+
+            assert_eq!(window.frame(), frame);
+
+            let handle = window.handle.hwnd().unwrap();
+            let (style, ex_style) = (wh::get_style(handle), wh::get_ex_style(handle));
+            match frame {
+                WindowFrame::Standard => {
+                    assert_eq!(style & WS_CAPTION, WS_CAPTION);
+                    assert_eq!(ex_style & WS_EX_TOOLWINDOW, 0);
+                },
+                WindowFrame::ToolWindow => {
+                    assert_eq!(style & WS_CAPTION, WS_CAPTION);
+                    assert_eq!(ex_style & WS_EX_TOOLWINDOW, WS_EX_TOOLWINDOW);
+                },
+                WindowFrame::Borderless => {
+                    assert_eq!(style & (WS_CAPTION | WS_THICKFRAME), 0);
+                    assert_eq!(style & WS_POPUP, WS_POPUP);
+                }
+            }
+        }
+    }
+
+    // Form values survive a round trip and are restored without raising events
+    {
+        use std::{rc::Rc, cell::Cell};
+
+        let (mut name, mut password, mut subscribe) = (TextInput::default(), TextInput::default(), CheckBox::default());
+        TextInput::builder().text("Ferris").parent(&_app.window).build(&mut name).unwrap();
+        TextInput::builder().text("secret").password(Some('*')).parent(&_app.window).build(&mut password).unwrap();
+        CheckBox::builder().check_state(CheckBoxState::Checked).parent(&_app.window).build(&mut subscribe).unwrap();
+
+        let controls = [("name", name.handle), ("password", password.handle), ("subscribe", subscribe.handle), ("test", _app.test.handle)];
+        let values = snapshot_values(&controls, false);
+        assert_eq!(values.len(), 2);
+        assert_eq!(values["name"], "Ferris");
+        assert_eq!(values["subscribe"], "true");
+
+        let text_events = Rc::new(Cell::new(0));
+        let text_events_handler = text_events.clone();
+        let handler = bind_event_handler(&name.handle, &_app.window.handle, move |evt, _evt_data, _handle| {
+            if evt == Event::OnTextInput {
+                text_events_handler.set(text_events_handler.get() + 1);
+            }
+        });
+
+        name.set_text("Crab");
+        subscribe.set_check_state(CheckBoxState::Unchecked);
+        assert_eq!(text_events.get(), 1);
+
+        // The keys do not depend on the order of the controls
+        let reordered = [("subscribe", subscribe.handle), ("name", name.handle)];
+        restore_values(&reordered, &values);
+        assert_eq!(name.text(), "Ferris");
+        assert_eq!(subscribe.check_state(), CheckBoxState::Checked);
+        assert_eq!(text_events.get(), 1);
+
+        unbind_event_handler(&handler);
+    }
+
+    // ENTER only submits an editable combobox when its dropdown is closed
+    #[cfg(feature = "combobox")]
+    {
+        use std::{mem, ptr, rc::Rc, cell::Cell};
+        use winapi::um::winuser::{GetComboBoxInfo, COMBOBOXINFO, PeekMessageW, TranslateMessage, DispatchMessageW, MSG, PM_REMOVE, WM_KEYDOWN, VK_RETURN};
+
+        let mut combo: ComboBox<&'static str> = ComboBox::default();
+        ComboBox::builder()
+            .flags(ComboBoxFlags::VISIBLE | ComboBoxFlags::EDITABLE)
+            .collection(vec!["Ferris", "Crab"])
+            .parent(&_app.window)
+            .build(&mut combo)
+            .unwrap();
+
+        let submits = Rc::new(Cell::new(0));
+        let submits_handler = submits.clone();
+        let handler = bind_event_handler(&combo.handle, &_app.window.handle, move |evt, _evt_data, _handle| {
+            if evt == Event::OnComboBoxSubmit {
+                submits_handler.set(submits_handler.get() + 1);
+            }
+        });
+
+        let press_enter = || unsafe {
+            let mut info: COMBOBOXINFO = mem::zeroed();
+            info.cbSize = mem::size_of::<COMBOBOXINFO>() as u32;
+            GetComboBoxInfo(combo.handle.hwnd().unwrap(), &mut info);
+            crate::win32::window_helper::send_message(info.hwndItem, WM_KEYDOWN, VK_RETURN as _, 0);
+
+            // The submit event is posted
+            let mut msg: MSG = mem::zeroed();
+            while PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        };
+
+        press_enter();
+        assert_eq!(submits.get(), 1);
+
+        combo.dropdown(true);
+        assert!(combo.dropdown_visible());
+        press_enter();
+        assert_eq!(submits.get(), 1);
+
+        combo.dropdown(false);
+        press_enter();
+        assert_eq!(submits.get(), 2);
+
+        unbind_event_handler(&handler);
+    }
+
+    // The automation id is read back by UI Automation clients
+    #[allow(non_snake_case)]
+    {
+        use std::ptr;
+        use winapi::RIDL;
+        use winapi::ctypes::{c_int, c_void};
+        use winapi::shared::{windef::HWND, winerror::S_OK, wtypes::VT_BSTR, wtypesbase::CLSCTX_INPROC_SERVER, guiddef::GUID};
+        use winapi::um::{combaseapi::CoCreateInstance, oaidl::VARIANT, unknwnbase::{IUnknown, IUnknownVtbl}, winnt::HRESULT};
+        use winapi::Interface;
+        use crate::win32::base_helper::from_wide_ptr;
+
+        const CLSID_CUI_AUTOMATION: GUID = GUID { Data1: 0xff48dba4, Data2: 0x60ef, Data3: 0x4201, Data4: [0xaa, 0x87, 0x54, 0x10, 0x3e, 0xef, 0x59, 0x4e] };
+        const UIA_AUTOMATION_ID_PROPERTY_ID: c_int = 30011;
+
+        // Only the methods up to the ones used by the test are declared
+        RIDL!{#[uuid(0x30cbe57d, 0xd9d0, 0x452a, 0xab, 0x13, 0x7a, 0xc5, 0xac, 0x48, 0x25, 0xee)]
+        interface IUIAutomation(IUIAutomationVtbl): IUnknown(IUnknownVtbl) {
+            fn CompareElements(el1: *mut IUnknown, el2: *mut IUnknown, areSame: *mut c_int,) -> HRESULT,
+            fn CompareRuntimeIds(runtimeId1: *mut c_void, runtimeId2: *mut c_void, areSame: *mut c_int,) -> HRESULT,
+            fn GetRootElement(root: *mut *mut IUIAutomationElement,) -> HRESULT,
+            fn ElementFromHandle(hwnd: HWND, element: *mut *mut IUIAutomationElement,) -> HRESULT,
+        }}
+
+        RIDL!{#[uuid(0xd22108aa, 0x8ac5, 0x49a5, 0x83, 0x7b, 0x37, 0xbb, 0xb3, 0xd7, 0x59, 0x1e)]
+        interface IUIAutomationElement(IUIAutomationElementVtbl): IUnknown(IUnknownVtbl) {
+            fn SetFocus() -> HRESULT,
+            fn GetRuntimeId(runtimeId: *mut *mut c_void,) -> HRESULT,
+            fn FindFirst(scope: c_int, condition: *mut IUnknown, found: *mut *mut c_void,) -> HRESULT,
+            fn FindAll(scope: c_int, condition: *mut IUnknown, found: *mut *mut c_void,) -> HRESULT,
+            fn FindFirstBuildCache(scope: c_int, condition: *mut IUnknown, cacheRequest: *mut IUnknown, found: *mut *mut c_void,) -> HRESULT,
+            fn FindAllBuildCache(scope: c_int, condition: *mut IUnknown, cacheRequest: *mut IUnknown, found: *mut *mut c_void,) -> HRESULT,
+            fn BuildUpdatedCache(cacheRequest: *mut IUnknown, updatedElement: *mut *mut c_void,) -> HRESULT,
+            fn GetCurrentPropertyValue(propertyId: c_int, retVal: *mut VARIANT,) -> HRESULT,
+        }}
+
+        #[link(name = "oleaut32")]
+        extern "system" {
+            fn VariantClear(var: *mut VARIANT) -> HRESULT;
+        }
+
+        let automation_id = |hwnd: HWND| unsafe {
+            let mut automation: *mut IUIAutomation = ptr::null_mut();
+            let result = CoCreateInstance(
+                &CLSID_CUI_AUTOMATION,
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &IUIAutomation::uuidof(),
+                (&mut automation as *mut *mut IUIAutomation) as *mut *mut c_void
+            );
+            assert_eq!(result, S_OK);
+
+            let mut element: *mut IUIAutomationElement = ptr::null_mut();
+            assert_eq!((&*automation).ElementFromHandle(hwnd, &mut element), S_OK);
+
+            let mut value: VARIANT = std::mem::zeroed();
+            assert_eq!((&*element).GetCurrentPropertyValue(UIA_AUTOMATION_ID_PROPERTY_ID, &mut value), S_OK);
+
+            let id = match value.n1.n2().vt as u32 {
+                VT_BSTR => from_wide_ptr(*value.n1.n2().n3.bstrVal(), None),
+                _ => String::new()
+            };
+
+            VariantClear(&mut value);
+            (&*element).Release();
+            (&*automation).Release();
+
+            id
+        };
+
+        let button = _app.test.handle.hwnd().unwrap();
+        _app.test.handle.set_automation_id(Some("test_button")).unwrap();
+        assert_eq!(automation_id(button), "test_button");
+
+        _app.test.handle.set_automation_id(None).unwrap();
+        assert_ne!(automation_id(button), "test_button");
+
+        _app.test.handle.set_automation_id(Some("test_button")).unwrap();
+        assert_eq!(automation_id(button), "test_button");
+    }
+
+    // Removing the theme of a control is reverted by restoring it
+    if _app.test.handle.set_themed(false).is_ok() {
+        assert_eq!(_app.test.handle.themed(), false);
+        _app.test.handle.set_themed(true).unwrap();
+        assert_eq!(_app.test.handle.themed(), true);
+    }
+
+    // Ampersands are displayed as is, unless the label has a mnemonic
+    {
+        assert_eq!(escape_ampersand("Tom & Jerry"), "Tom && Jerry");
+        assert_eq!(escape_ampersand("&&"), "&&&&");
+
+        let (mut literal, mut mnemonic) = (Label::default(), Label::default());
+        Label::builder().text("Tom & Jerry").parent(&_app.window).build(&mut literal).unwrap();
+        Label::builder().text("&Name").label_for(Some(&_app.test)).parent(&_app.window).build(&mut mnemonic).unwrap();
+
+        assert_eq!(literal.mnemonic(), false);
+        assert_eq!(mnemonic.mnemonic(), true);
+
+        literal.set_mnemonic(true);
+        assert_eq!(literal.mnemonic(), true);
+        literal.set_mnemonic(false);
+        assert_eq!(literal.mnemonic(), false);
+    }
+
+    // Binding an event that the control never sends is an error
+    {
+        let mut label = Label::default();
+        Label::builder().text("Events").parent(&_app.window).build(&mut label).unwrap();
+
+        assert!(_app.test.handle.supports_event(Event::OnButtonClick));
+        assert!(label.handle.supports_event(Event::OnMousePress(MousePressEvent::MousePressRightDown)));
+        assert!(!label.handle.supports_event(Event::OnTextInput));
+        assert!(!_app.test.handle.supports_event(Event::OnInit));
+        assert!(_app.window.handle.supports_event(Event::OnInit));
+        assert!(ControlHandle::NoHandle.supported_events().is_empty());
+
+        let window = &_app.window.handle;
+        assert!(bind_checked_event_handler(&label.handle, window, &[Event::OnLabelClick, Event::OnTextInput], |_, _, _| {}).is_err());
+        let handler = bind_checked_event_handler(&_app.test.handle, window, &[Event::OnButtonClick], |_, _, _| {}).unwrap();
+        unbind_event_handler(&handler);
+    }
+
+    // Toast windows are destroyed when their timer fires
+    {
+        use std::time::{Duration, Instant};
+        use winapi::um::winuser::{EnumThreadWindows, PeekMessageW, TranslateMessage, DispatchMessageW, MSG, PM_REMOVE, WS_EX_LAYERED, WS_EX_NOACTIVATE};
+        use winapi::um::processthreadsapi::GetCurrentThreadId;
+        use winapi::shared::{windef::HWND, minwindef::{BOOL, LPARAM}};
+        use crate::win32::window_helper as wh;
+
+        unsafe extern "system" fn count_toast(hwnd: HWND, count: LPARAM) -> BOOL {
+            let toast_style = WS_EX_LAYERED | WS_EX_NOACTIVATE;
+            if wh::get_ex_style(hwnd) & toast_style == toast_style {
+                *(count as *mut usize) += 1;
+            }
+            1
+        }
+
+        let toasts = || unsafe {
+            let mut count = 0usize;
+            EnumThreadWindows(GetCurrentThreadId(), Some(count_toast), &mut count as *mut usize as LPARAM);
+            count
+        };
+
+        toast("Saved", 20).unwrap();
+        toast("Saved again", 20).unwrap();
+        assert_eq!(toasts(), 2);
+
+        let start = Instant::now();
+        while toasts() > 0 && start.elapsed() < Duration::from_millis(1000) {
+            unsafe {
+                let mut msg: MSG = std::mem::zeroed();
+                while PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                    TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            }
+
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(toasts(), 0);
+    }
+
+    // Dragging a list box item moves it in the control and in the collection
+    #[cfg(feature = "listbox")]
+    {
+        use std::{mem, ptr, rc::Rc, cell::RefCell};
+        use winapi::um::winuser::{LB_GETITEMRECT, WM_LBUTTONDOWN, WM_MOUSEMOVE, WM_LBUTTONUP, MK_LBUTTON, PeekMessageW, TranslateMessage, DispatchMessageW, MSG, PM_REMOVE};
+        use winapi::shared::{windef::RECT, minwindef::MAKELONG};
+        use crate::win32::window_helper as wh;
+
+        let mut list: ListBox<&'static str> = ListBox::default();
+        ListBox::builder()
+            .collection(vec!["A", "B", "C", "D"])
+            .size((100, 100))
+            .parent(&_app.window)
+            .build(&mut list)
+            .unwrap();
+
+        list.set_drag_reorder(true);
+        list.set_item_data(0, 42);
+
+        let moves = Rc::new(RefCell::new(Vec::new()));
+        let moves_handler = moves.clone();
+        let handler = bind_event_handler(&list.handle, &_app.window.handle, move |evt, evt_data, _handle| {
+            if let (Event::OnListBoxItemMoved, EventData::OnListBoxItemMoved { from, to }) = (evt, evt_data) {
+                moves_handler.borrow_mut().push((from, to));
+            }
+        });
+
+        let hwnd = list.handle.hwnd().unwrap();
+        let item_rect = |index: usize| unsafe {
+            let mut rect: RECT = mem::zeroed();
+            wh::send_message(hwnd, LB_GETITEMRECT, index, &mut rect as *mut RECT as _);
+            rect
+        };
+
+        // Drag the first item to the lower half of the third item
+        let (first, third) = (item_rect(0), item_rect(2));
+        let from = MAKELONG(5, ((first.top + first.bottom) / 2) as u16) as isize;
+        let to = MAKELONG(5, (third.bottom - 2) as u16) as isize;
+        wh::send_message(hwnd, WM_LBUTTONDOWN, MK_LBUTTON, from);
+        wh::send_message(hwnd, WM_MOUSEMOVE, MK_LBUTTON, to);
+        wh::send_message(hwnd, WM_LBUTTONUP, 0, to);
+
+        // The event is posted
+        unsafe {
+            let mut msg: MSG = mem::zeroed();
+            while PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+
+        assert_eq!(&*moves.borrow(), &[(0, 2)]);
+        assert_eq!(&*list.collection(), &["B", "C", "A", "D"]);
+        assert_eq!(list.find_item("A", true), Some(2));
+        assert_eq!(list.item_data(2), Some(42));
+        assert_eq!(list.selection(), Some(2));
+
+        unbind_event_handler(&handler);
+    }
+
+    // The dropdown list grows to fit its widest item, up to the maximum width
+    #[cfg(feature = "combobox")]
+    {
+        use winapi::um::winuser::CB_GETHORIZONTALEXTENT;
+
+        let mut combo: ComboBox<&'static str> = ComboBox::default();
+        ComboBox::builder()
+            .flags(ComboBoxFlags::VISIBLE | ComboBoxFlags::H_SCROLL)
+            .collection(vec!["A", "B"])
+            .size((100, 25))
+            .parent(&_app.window)
+            .build(&mut combo)
+            .unwrap();
+
+        let extent = || unsafe { combo.handle.send_message(CB_GETHORIZONTALEXTENT, 0, 0) };
+
+        let short = combo.fit_dropdown_width(None);
+        assert_eq!(short, combo.dropdown_width());
+        assert!(short >= 100);
+
+        combo.push("A very long item that cannot be displayed in the width of the combobox");
+        let long = combo.fit_dropdown_width(None);
+        assert!(long > short);
+        assert_eq!(extent(), 0);
+
+        assert_eq!(combo.fit_dropdown_width(Some(long - 50)), long - 50);
+        assert!(extent() > 0);
+    }
+
+    // A layered window takes the size of its 32 bits bitmap
+    {
+        use std::{mem, ptr};
+        use winapi::um::winuser::WS_EX_LAYERED;
+        use winapi::um::wingdi::{CreateDIBSection, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS};
+        use crate::win32::window_helper as wh;
+
+        let dib = |bits: u16| unsafe {
+            let mut info: BITMAPINFO = mem::zeroed();
+            info.bmiHeader.biSize = mem::size_of::<BITMAPINFOHEADER>() as u32;
+            info.bmiHeader.biWidth = 40;
+            info.bmiHeader.biHeight = -20;
+            info.bmiHeader.biPlanes = 1;
+            info.bmiHeader.biBitCount = bits;
+            info.bmiHeader.biCompression = BI_RGB;
+
+            let mut pixels = ptr::null_mut();
+            let handle = CreateDIBSection(ptr::null_mut(), &info, DIB_RGB_COLORS, &mut pixels, ptr::null_mut(), 0);
+            assert!(!handle.is_null());
+
+            // An opaque white square on a transparent background. Transparent pixels are all zeros once premultiplied.
+            if bits == 32 {
+                let pixels = pixels as *mut u32;
+                for y in 5..15 {
+                    for x in 15..25 {
+                        *pixels.offset(y * 40 + x) = 0xFFFFFFFF;
+                    }
+                }
+            }
+
+            Bitmap { handle: handle as _, owned: true }
+        };
+
+        let mut splash = Window::default();
+        Window::builder()
+            .flags(WindowFlags::POPUP)
+            .size((100, 100))
+            .build(&mut splash)
+            .unwrap();
+
+        match splash.set_layered_bitmap(&dib(24)) {
+            Err(NwgError::NotSupported(_)) => {},
+            r => panic!("Unexpected result: {:?}", r)
+        }
+        assert_eq!(wh::get_ex_style(splash.handle.hwnd().unwrap()) & WS_EX_LAYERED, 0);
+
+        splash.set_layered_bitmap(&dib(32)).unwrap();
+        assert_eq!(splash.size(), (40, 20));
+        assert_eq!(wh::get_ex_style(splash.handle.hwnd().unwrap()) & WS_EX_LAYERED, WS_EX_LAYERED);
+    }
+
+    // The parent is scrolled by the smallest amount that shows the control, within the scroll range
+    {
+        use std::mem;
+        use winapi::um::winuser::{SetScrollInfo, GetScrollPos, SCROLLINFO, SIF_RANGE, SIF_PAGE, SIF_POS, SB_VERT};
+
+        let mut form = Window::default();
+        Window::builder()
+            .flags(WindowFlags::POPUP | WindowFlags::VISIBLE)
+            .size((200, 100))
+            .build(&mut form)
+            .unwrap();
+
+        let (mut top, mut middle, mut bottom) = (Button::default(), Button::default(), Button::default());
+        Button::builder().position((10, 10)).size((100, 30)).parent(&form).build(&mut top).unwrap();
+        Button::builder().position((10, 300)).size((100, 30)).parent(&form).build(&mut middle).unwrap();
+        Button::builder().position((10, 380)).size((100, 30)).parent(&form).build(&mut bottom).unwrap();
+
+        let form_hwnd = form.handle.hwnd().unwrap();
+        unsafe {
+            let mut info: SCROLLINFO = mem::zeroed();
+            info.cbSize = mem::size_of::<SCROLLINFO>() as u32;
+            info.fMask = SIF_RANGE | SIF_PAGE | SIF_POS;
+            info.nMax = 399;
+            info.nPage = 100;
+            SetScrollInfo(form_hwnd, SB_VERT as i32, &info, 0);
+        }
+
+        let scroll_pos = || unsafe { GetScrollPos(form_hwnd, SB_VERT as i32) };
+
+        assert_eq!(top.handle.ensure_visible(), false);
+        assert_eq!(scroll_pos(), 0);
+
+        // The bottom of the button is aligned with the bottom of the view
+        assert_eq!(middle.handle.ensure_visible(), true);
+        assert_eq!(scroll_pos(), 230);
+        assert_eq!(middle.position(), (10, 70));
+        assert_eq!(middle.handle.ensure_visible(), false);
+
+        // The scroll position stops at the end of the range
+        assert_eq!(bottom.handle.ensure_visible(), true);
+        assert_eq!(scroll_pos(), 300);
+        assert_eq!(bottom.position(), (10, 80));
+
+        // Scrolling back up shows the top of the button
+        assert_eq!(top.handle.ensure_visible(), true);
+        assert_eq!(scroll_pos(), 10);
+        assert_eq!(top.position(), (10, 0));
+    }
+
+    // Grid cells are sized by the column and row weights
+    {
+        let mut panel = Window::default();
+        Window::builder()
+            .flags(WindowFlags::POPUP)
+            .size((400, 200))
+            .build(&mut panel)
+            .unwrap();
+
+        let (mut left, mut center, mut right, mut footer) = (Button::default(), Button::default(), Button::default(), Button::default());
+        for button in [&mut left, &mut center, &mut right, &mut footer].iter_mut() {
+            Button::builder().parent(&panel).build(button).unwrap();
+        }
+
+        let grid = GridLayout::default();
+        GridLayout::builder()
+            .parent(&panel)
+            .margin([0, 0, 0, 0])
+            .spacing(0)
+            .column_weights(&[1, 2, 1])
+            .row_weights(&[3, 1])
+            .child(0, 0, &left)
+            .child(1, 0, &center)
+            .child(2, 0, &right)
+            .child_item(GridLayoutItem::new(&footer, 1, 1, 2, 1))
+            .build(&grid)
+            .unwrap();
+
+        grid.resize(400, 200);
+        assert_eq!((left.position(), left.size()), ((0, 0), (100, 150)));
+        assert_eq!((center.position(), center.size()), ((100, 0), (200, 150)));
+        assert_eq!((right.position(), right.size()), ((300, 0), (100, 150)));
+        assert_eq!((footer.position(), footer.size()), ((100, 150), (300, 50)));
+
+        // The pixels that cannot be split evenly go to the first cells
+        grid.resize(402, 200);
+        assert_eq!(left.size(), (101, 150));
+        assert_eq!(center.size(), (201, 150));
+        assert_eq!(right.size(), (100, 150));
+    }
+
+    // Double buffered windows lay out their children like other windows
+    {
+        use winapi::um::winuser::WS_EX_COMPOSITED;
+        use crate::win32::window_helper as wh;
+
+        let mut buffered = Window::default();
+        Window::builder()
+            .flags(WindowFlags::POPUP)
+            .size((300, 100))
+            .double_buffered(true)
+            .build(&mut buffered)
+            .unwrap();
+
+        let hwnd = buffered.handle.hwnd().unwrap();
+        assert!(buffered.handle.double_buffered());
+        assert_eq!(wh::get_ex_style(hwnd) & WS_EX_COMPOSITED, WS_EX_COMPOSITED);
+
+        let (mut first, mut second) = (Button::default(), Button::default());
+        Button::builder().parent(&buffered).build(&mut first).unwrap();
+        Button::builder().parent(&buffered).build(&mut second).unwrap();
+
+        let grid = GridLayout::default();
+        GridLayout::builder()
+            .parent(&buffered)
+            .margin([0, 0, 0, 0])
+            .spacing(0)
+            .column_weights(&[2, 1])
+            .child(0, 0, &first)
+            .child(1, 0, &second)
+            .build(&grid)
+            .unwrap();
+
+        // The layout follows the window size
+        buffered.set_size(600, 100);
+        assert_eq!(first.size(), (400, 100));
+        assert_eq!((second.position(), second.size()), ((400, 0), (200, 100)));
+
+        buffered.handle.set_double_buffered(false);
+        assert!(!buffered.handle.double_buffered());
+        buffered.handle.set_double_buffered(true);
+        assert!(buffered.handle.double_buffered());
+    }
+
+    // Dropping an extern window must not destroy the window
+    #[cfg(feature = "extern-window")]
+    {
+        use winapi::um::winuser::IsWindow;
+
+        let mut invalid = ExternWindow::default();
+        assert!(ExternWindow::builder().build(&mut invalid).is_err());
+
+        let hwnd = _app.window.handle.hwnd().unwrap();
+        let mut host = ExternWindow::default();
+        ExternWindow::builder().hwnd(hwnd).build(&mut host).unwrap();
+        assert_eq!(host.size(), _app.window.size());
+        assert_eq!(host.text(), "Other Tests");
+        assert_eq!(host.class_name(), "NativeWindowsGuiWindow");
+
+        let mut button = Button::default();
+        Button::builder().text("Extern").parent(&host).build(&mut button).unwrap();
+        assert_eq!(crate::win32::window_helper::get_window_parent(button.handle.hwnd().unwrap()), hwnd);
+        drop(button);
+
+        drop(host);
+        assert!(unsafe { IsWindow(hwnd) } != 0);
+    }
+
+    // Wide buffers must stop at the first null character
+    {
+        use crate::controls::WideBuffer;
+
+        let empty = WideBuffer::new(10);
+        assert_eq!(empty.len(), 10);
+        assert_eq!(empty.to_string(), "");
+
+        assert_eq!(WideBuffer::new(0).to_string(), "");
+        assert_eq!(WideBuffer::from_str("").len(), 1);
+        assert_eq!(WideBuffer::from_str("").to_string(), "");
+
+        let text = WideBuffer::from_str("🦀 text");
+        assert_eq!(text.len(), 8);
+        assert_eq!(text.to_string(), "🦀 text");
+
+        let mut buffer = WideBuffer::new(5);
+        unsafe {
+            let ptr = buffer.as_lparam() as *mut u16;
+            *ptr = 'a' as u16;
+            *ptr.offset(1) = 'b' as u16;
+            *ptr.offset(3) = 'c' as u16;
+        }
+        assert_eq!(buffer.to_string(), "ab");
+
+        // A buffer filled to the end without a null character is read whole
+        let mut full = WideBuffer::new(2);
+        unsafe {
+            let ptr = full.as_wparam() as *mut u16;
+            *ptr = 'x' as u16;
+            *ptr.offset(1) = 'y' as u16;
+        }
+        assert_eq!(full.to_string(), "xy");
+    }
+
     //dispatch_thread_events();
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+/*!
+Accessibility helpers.
+
+Instead of implementing `IAccessible` for every control, NWG uses the MSAA "dynamic annotation" API (`IAccPropServices`)
+to override the properties that the default system proxies report to screen readers.
+*/
+#![allow(non_snake_case)]
+
+use winapi::RIDL;
+use winapi::shared::guiddef::GUID;
+use winapi::shared::minwindef::DWORD;
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::S_OK;
+use winapi::um::oaidl::VARIANT;
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::um::winnt::{HRESULT, LPCWSTR};
+use winapi::ctypes::{c_int, c_void};
+use super::base_helper::to_utf16;
+use crate::NwgError;
+use std::ptr;
+
+#[allow(non_camel_case_types)]
+type MSAAPROPID = GUID;
+
+const OBJID_CLIENT: DWORD = 0xFFFFFFFC;
+const CHILDID_SELF: DWORD = 0;
+
+const CLSID_ACC_PROP_SERVICES: GUID = GUID { Data1: 0xb5f8350b, Data2: 0x0548, Data3: 0x48b1, Data4: [0xa6, 0xee, 0x88, 0xbd, 0x00, 0xb4, 0xa5, 0xe7] };
+const PROPID_ACC_NAME: MSAAPROPID = GUID { Data1: 0x608d3df8, Data2: 0x8128, Data3: 0x4aa7, Data4: [0xa4, 0x28, 0xf5, 0x5e, 0x49, 0x26, 0x72, 0x91] };
+
+/// The UI Automation properties can be annotated with their GUID
+const AUTOMATION_ID_PROPERTY: MSAAPROPID = GUID { Data1: 0xc82c0500, Data2: 0xb60e, Data3: 0x4310, Data4: [0xa2, 0x67, 0x30, 0x3c, 0x53, 0x1f, 0x8e, 0xe5] };
+
+RIDL!{#[uuid(0x6e26e776, 0x04f0, 0x495d, 0x80, 0xe4, 0x33, 0x30, 0x35, 0x2e, 0x31, 0x69)]
+interface IAccPropServices(IAccPropServicesVtbl): IUnknown(IUnknownVtbl) {
+    fn SetPropValue(pIDString: *const u8, dwIDStringLen: DWORD, idProp: MSAAPROPID, var: VARIANT,) -> HRESULT,
+    fn SetPropServer(pIDString: *const u8, dwIDStringLen: DWORD, paProps: *const MSAAPROPID, cProps: c_int, pServer: *mut IUnknown, annoScope: DWORD,) -> HRESULT,
+    fn ClearProps(pIDString: *const u8, dwIDStringLen: DWORD, paProps: *const MSAAPROPID, cProps: c_int,) -> HRESULT,
+    fn SetHwndProp(hwnd: HWND, idObject: DWORD, idChild: DWORD, idProp: MSAAPROPID, var: VARIANT,) -> HRESULT,
+    fn SetHwndPropStr(hwnd: HWND, idObject: DWORD, idChild: DWORD, idProp: MSAAPROPID, str: LPCWSTR,) -> HRESULT,
+    fn SetHwndPropServer(hwnd: HWND, idObject: DWORD, idChild: DWORD, paProps: *const MSAAPROPID, cProps: c_int, pServer: *mut IUnknown, annoScope: DWORD,) -> HRESULT,
+    fn ClearHwndProps(hwnd: HWND, idObject: DWORD, idChild: DWORD, paProps: *const MSAAPROPID, cProps: c_int,) -> HRESULT,
+}}
+
+
+/// Create the annotation service, execute `cb` and release the service
+unsafe fn with_prop_services<F>(cb: F) -> Result<(), NwgError>
+    where F: FnOnce(&IAccPropServices) -> HRESULT
+{
+    use winapi::um::combaseapi::CoCreateInstance;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+    use winapi::Interface;
+
+    let mut services: *mut IAccPropServices = ptr::null_mut();
+    let result = CoCreateInstance(
+        &CLSID_ACC_PROP_SERVICES,
+        ptr::null_mut(),
+        CLSCTX_INPROC_SERVER,
+        &IAccPropServices::uuidof(),
+        (&mut services as *mut *mut IAccPropServices) as *mut *mut c_void
+    );
+
+    if result != S_OK {
+        return Err(NwgError::not_supported("The accessibility annotation service is not available"));
+    }
+
+    let result = cb(&*services);
+    (&*services).Release();
+
+    match result {
+        S_OK => Ok(()),
+        code => Err(NwgError::not_supported(format!("Failed to annotate the control (HRESULT {:#X})", code)))
+    }
+}
+
+/// Override the name reported by the accessibility proxy of a window. If `name` is None, restore the default name.
+pub fn set_accessible_name(hwnd: HWND, name: Option<&str>) -> Result<(), NwgError> {
+    set_string_prop(hwnd, PROPID_ACC_NAME, name)
+}
+
+/// Override the UI Automation `AutomationId` of a window. If `id` is None, restore the default id.
+pub fn set_automation_id(hwnd: HWND, id: Option<&str>) -> Result<(), NwgError> {
+    set_string_prop(hwnd, AUTOMATION_ID_PROPERTY, id)
+}
+
+/// Annotate a string property of a window or clear the annotation if `value` is None
+fn set_string_prop(hwnd: HWND, prop: MSAAPROPID, value: Option<&str>) -> Result<(), NwgError> {
+    unsafe {
+        with_prop_services(|services| match value {
+            Some(value) => {
+                let value = to_utf16(value);
+                services.SetHwndPropStr(hwnd, OBJID_CLIENT, CHILDID_SELF, prop, value.as_ptr())
+            },
+            None => {
+                let props = [prop];
+                services.ClearHwndProps(hwnd, OBJID_CLIENT, CHILDID_SELF, props.as_ptr(), props.len() as c_int)
+            }
+        })
+    }
+}
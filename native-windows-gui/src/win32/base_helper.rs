@@ -29,6 +29,21 @@ pub fn to_utf16<'a>(s: &'a str) -> Vec<u16> {
       .collect()
 }
 
+/**
+    Double every `&` in `text` so that it is displayed as a literal ampersand instead of marking a mnemonic.
+    Use this on user provided text set on buttons, check boxes, radio buttons or menu items (ex: "Tom & Jerry").
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn set_name(button: &nwg::Button, name: &str) {
+        button.set_text(&nwg::escape_ampersand(name));
+    }
+    ```
+*/
+pub fn escape_ampersand<'a>(text: &'a str) -> String {
+    text.replace('&', "&&")
+}
+
 /**
     Decode a raw utf16 string. Should be null terminated.
 */
@@ -45,7 +60,6 @@ pub fn from_utf16(s: &[u16]) -> String {
 /**
     Read a string from a wide char pointer. Undefined behaviour if [ptr] is not null terminated.
 */
-#[cfg(any(feature = "file-dialog", feature = "winnls"))]
 pub unsafe fn from_wide_ptr(ptr: *mut u16, length: Option<usize>) -> String {
     use std::slice::from_raw_parts;
 
@@ -1,7 +1,15 @@
 use crate::controls::ControlHandle;
+use crate::NwgError;
 use super::base_helper::{to_utf16};
 use winapi::um::winuser::{CF_BITMAP, CF_TEXT, CF_UNICODETEXT};
 use winapi::um::winnt::HANDLE;
+use winapi::shared::windef::HWND;
+
+/// Number of times `OpenClipboard` is called before giving up. Another application can hold the clipboard for a short time.
+const OPEN_ATTEMPTS: u32 = 5;
+
+/// Delay in ms between two `OpenClipboard` attempts
+const OPEN_RETRY_DELAY: u64 = 10;
 
 
 #[derive(Copy, Clone)]
@@ -151,71 +159,80 @@ impl Clipboard {
         This is a high level function that handles `open` and `close`
     */
     pub fn set_data_text<'a, C: Into<ControlHandle>>(handle: C, text: &'a str) {
-        use winapi::um::winuser::SetClipboardData;
-        use winapi::um::stringapiset::MultiByteToWideChar;
-        use winapi::um::winnls::CP_UTF8;
-        use winapi::shared::basetsd::SIZE_T;
-        use winapi::um::winbase::{GlobalAlloc, GlobalLock, GlobalFree, GlobalUnlock, GMEM_MOVEABLE};
-        use core::{mem, ptr};
-
-        let size = unsafe {
-            MultiByteToWideChar(CP_UTF8, 0, text.as_ptr() as *const _, text.len() as _, ptr::null_mut(), 0)
-        };
-
-        if size == 0 {
+        let handle = handle.into().hwnd().expect("Control should be a window");
+        if text.is_empty() {
             return;
         }
 
-        let alloc_size = (mem::size_of::<u16>() * (size as usize + 1)) as SIZE_T;
-        let alloc = unsafe { GlobalAlloc(GMEM_MOVEABLE, alloc_size) };
+        unsafe { drop(write_text(handle, text)); }
+    }
 
-        unsafe {
-            let locked_ptr = GlobalLock(alloc) as *mut u16;
-            assert!(!locked_ptr.is_null());
-            MultiByteToWideChar(CP_UTF8, 0, text.as_ptr() as *const _, text.len() as _, locked_ptr, size);
-            ptr::write(locked_ptr.offset(size as isize), 0);
-            GlobalUnlock(alloc);
-        }
+    /**
+        Fill the clipboard with the whole text of `control`, even if nothing is selected (unlike `WM_COPY`).
+        Ex: a "Copy all" button next to a readonly text box. The data use the `ClipboardFormat::UnicodeText` format.
+
+        `control` becomes the owner of the clipboard. Returns an error if the clipboard could not be opened (ex: another
+        application is using it) or if the data could not be set. The clipboard is always closed when the function returns.
 
-        Clipboard::open(handle);
-        Clipboard::empty();
+        This function will panic if the control is not HWND based.
+    */
+    pub fn copy_control_text<C: Into<ControlHandle>>(control: C) -> Result<(), NwgError> {
+        use super::window_helper::get_window_text;
 
+        let handle = control.into().hwnd().expect("Control should be a window");
         unsafe {
-            if SetClipboardData(CF_UNICODETEXT, alloc as _).is_null() {
-                GlobalFree(alloc);
-            }
+            let text = get_window_text(handle);
+            write_text(handle, &text)
         }
-
-        Clipboard::close();
     }
 
     /**
-        Return the current text value in the clipboard (if there is one).
-        This function will return the text if the clipboard has either the `UnicodeText` format or the `Text` format.
+        Fill the clipboard with `text`, without requiring a control. Ex: an "Export" button that copies generated text.
+        The data use the `ClipboardFormat::UnicodeText` format. An empty text replaces the clipboard data with an empty text.
 
-        If the clipboard do not have a text format OR the text data is not a valid utf-8 sequence, this function will return `None`.
+        Opening the clipboard is tried a few times because another application may be using it.
+        Returns an error if the clipboard could not be opened or if the data could not be set.
     */
-    pub fn data_text<C: Into<ControlHandle>>(handle: C) -> Option<String> {
-        use ClipboardFormat::*;
-        let mut data = None;
+    pub fn set_text(text: &str) -> Result<(), NwgError> {
+        use winapi::um::winuser::{CreateWindowExW, DestroyWindow, HWND_MESSAGE};
+        use std::ptr;
 
-        Clipboard::open(handle);
+        // The clipboard data can only be set if the clipboard has an owner window
+        let class_name = to_utf16("STATIC");
+        let owner = unsafe {
+            CreateWindowExW(0, class_name.as_ptr(), ptr::null(), 0, 0, 0, 0, 0, HWND_MESSAGE, ptr::null_mut(), ptr::null_mut(), ptr::null_mut())
+        };
+
+        if owner.is_null() {
+            return Err(NwgError::last_win32_error("CreateWindowExW"));
+        }
 
         unsafe {
-            if Clipboard::has_format(UnicodeText) {
-                let handle = Clipboard::data_handle(UnicodeText).unwrap();
-                data = from_wide_ptr(handle.cast());
-                handle.release();
-            } else if Clipboard::has_format(Text) {
-                let handle = Clipboard::data_handle(Text).unwrap();
-                data = from_ptr(handle.cast());
-                handle.release();
-            }
+            let result = write_text(owner, text);
+            DestroyWindow(owner);
+            result
         }
+    }
 
-        Clipboard::close();
+    /**
+        Return the text in the clipboard, without requiring a control.
+        Returns `None` if the clipboard is empty, if it does not hold text, or if it could not be opened.
 
-        data
+        Text copied by applications that only set the `ClipboardFormat::Text` format is converted by the system.
+    */
+    pub fn text() -> Option<String> {
+        unsafe { read_text(std::ptr::null_mut()) }
+    }
+
+    /**
+        Return the current text value in the clipboard (if there is one).
+        This function will return the text if the clipboard has either the `UnicodeText` format or the `Text` format (converted by the system).
+
+        If the clipboard do not have a text format OR the text data is not a valid unicode sequence, this function will return `None`.
+    */
+    pub fn data_text<C: Into<ControlHandle>>(handle: C) -> Option<String> {
+        let handle = handle.into().hwnd().expect("Control should be a window");
+        unsafe { read_text(handle) }
     }
 
     /**
@@ -371,35 +388,109 @@ impl Clipboard {
 }
 
 
-unsafe fn from_wide_ptr(ptr: *const u16) -> Option<String> {
-    use std::slice::from_raw_parts;
-    use std::ffi::OsString;
-    use std::os::windows::ffi::OsStringExt;
+/// Open the clipboard, trying again a few times if another application is using it
+unsafe fn open_clipboard(owner: HWND) -> bool {
+    use winapi::um::winuser::OpenClipboard;
+    use std::{thread, time::Duration};
 
-    let mut length: isize = 0;
-    while *&*ptr.offset(length) != 0 {
-        length += 1;
+    for attempt in 0..OPEN_ATTEMPTS {
+        if attempt > 0 {
+            thread::sleep(Duration::from_millis(OPEN_RETRY_DELAY));
+        }
+
+        if OpenClipboard(owner) != 0 {
+            return true;
+        }
     }
 
-    let array: &[u16] = from_raw_parts(ptr, length as usize);
+    false
+}
 
-    OsString::from_wide(&array)
-        .into_string()
-        .ok()
+/// Read the `CF_UNICODETEXT` data of the clipboard. Returns `None` if the clipboard does not hold text or could not be opened.
+unsafe fn read_text(owner: HWND) -> Option<String> {
+    use winapi::um::winuser::{GetClipboardData, CloseClipboard};
+    use winapi::um::winbase::{GlobalLock, GlobalUnlock};
+
+    if !open_clipboard(owner) {
+        return None;
+    }
+
+    let mut text = None;
+    let data = GetClipboardData(CF_UNICODETEXT);
+    if !data.is_null() {
+        let ptr = GlobalLock(data) as *const u16;
+        if !ptr.is_null() {
+            text = from_wide_ptr(ptr);
+            GlobalUnlock(data);
+        }
+    }
+
+    CloseClipboard();
+
+    text
 }
 
-unsafe fn from_ptr(ptr: *const u8) -> Option<String> {
+/// Copy `text` in a global allocation and set it as the `CF_UNICODETEXT` data of the clipboard.
+/// The allocation is freed if the clipboard does not take it and the clipboard is closed on every path.
+unsafe fn write_text(owner: HWND, text: &str) -> Result<(), NwgError> {
+    use winapi::um::winuser::{EmptyClipboard, SetClipboardData, CloseClipboard};
+    use winapi::um::winbase::{GlobalAlloc, GlobalLock, GlobalFree, GlobalUnlock, GMEM_MOVEABLE};
+    use std::{mem, ptr};
+
+    // Includes the null character
+    let text = to_utf16(text);
+
+    let alloc = GlobalAlloc(GMEM_MOVEABLE, mem::size_of::<u16>() * text.len());
+    if alloc.is_null() {
+        return Err(NwgError::last_win32_error("GlobalAlloc"));
+    }
+
+    let locked_ptr = GlobalLock(alloc) as *mut u16;
+    if locked_ptr.is_null() {
+        let error = NwgError::last_win32_error("GlobalLock");
+        GlobalFree(alloc);
+        return Err(error);
+    }
+
+    ptr::copy_nonoverlapping(text.as_ptr(), locked_ptr, text.len());
+    GlobalUnlock(alloc);
+
+    if !open_clipboard(owner) {
+        let error = NwgError::last_win32_error("OpenClipboard");
+        GlobalFree(alloc);
+        return Err(error);
+    }
+
+    EmptyClipboard();
+
+    // On success, the system owns the allocation
+    let result = match SetClipboardData(CF_UNICODETEXT, alloc as HANDLE).is_null() {
+        true => {
+            let error = NwgError::last_win32_error("SetClipboardData");
+            GlobalFree(alloc);
+            Err(error)
+        },
+        false => Ok(())
+    };
+
+    CloseClipboard();
+
+    result
+}
+
+unsafe fn from_wide_ptr(ptr: *const u16) -> Option<String> {
     use std::slice::from_raw_parts;
-    use std::str;
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
 
     let mut length: isize = 0;
     while *&*ptr.offset(length) != 0 {
         length += 1;
     }
 
-    let array: &[u8] = from_raw_parts(ptr, length as usize);
+    let array: &[u16] = from_raw_parts(ptr, length as usize);
 
-    str::from_utf8(array)
-        .map(|s| s.into())
+    OsString::from_wide(&array)
+        .into_string()
         .ok()
 }
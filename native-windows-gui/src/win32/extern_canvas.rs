@@ -28,12 +28,18 @@ pub fn create_extern_canvas_classes() -> Result<(), NwgError>  {
 
 
 unsafe extern "system" fn extern_canvas_proc(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
-    use winapi::um::winuser::{WM_CREATE, WM_ERASEBKGND};
-    use winapi::um::winuser::DefWindowProcW;
+    use winapi::um::winuser::{WM_CREATE, WM_ERASEBKGND, WM_SETFOCUS, WM_KILLFOCUS, WM_UPDATEUISTATE};
+    use winapi::um::winuser::{DefWindowProcW, InvalidateRect};
 
     let handled = match msg {
         WM_CREATE => Some(0),
         WM_ERASEBKGND => Some(1),
+        WM_SETFOCUS | WM_KILLFOCUS | WM_UPDATEUISTATE => {
+            // The focus rectangle of the canvas must be drawn or erased
+            let result = DefWindowProcW(hwnd, msg, w, l);
+            InvalidateRect(hwnd, ptr::null(), 1);
+            Some(result)
+        },
         _ => None
     };
 
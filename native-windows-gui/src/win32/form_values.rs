@@ -0,0 +1,120 @@
+use crate::controls::ControlHandle;
+use super::window_helper as wh;
+use winapi::shared::windef::HWND;
+use std::collections::HashMap;
+
+
+/// The kind of value held by a control
+#[derive(Copy, Clone, PartialEq)]
+enum ValueKind {
+    Text,
+    Password,
+    Choice,
+    Check,
+}
+
+/**
+    Save the value of input controls: the text of the text inputs and the text boxes, the selected text of the combo boxes,
+    and the state of the check boxes and the radio buttons ("true", "false" or "indeterminate").
+
+    Each control is given with the name used as its key in the returned map. The names do not depend on the order of the controls,
+    so the map can be saved to a file and reloaded with `restore_values` even if the window changed in the meantime.
+    Controls that are not input controls (labels, buttons, lists, ...) are ignored.
+
+    The content of password inputs is not saved unless `include_passwords` is `true`.
+
+    This function panics if one of the controls is not a window-like control.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn save_form(name: &nwg::TextInput, subscribe: &nwg::CheckBox) -> String {
+        let values = nwg::snapshot_values(&[("name", name.handle), ("subscribe", subscribe.handle)], false);
+        values.iter().map(|(k, v)| format!("{}={}\n", k, v)).collect()
+    }
+    ```
+*/
+pub fn snapshot_values(controls: &[(&str, ControlHandle)], include_passwords: bool) -> HashMap<String, String> {
+    use winapi::um::winuser::{BM_GETCHECK, BST_CHECKED, BST_INDETERMINATE};
+
+    let mut values = HashMap::new();
+    for &(name, control) in controls {
+        let handle = control.hwnd().expect("snapshot_values only works with window-like controls");
+        let kind = match value_kind(handle) {
+            Some(kind) => kind,
+            None => { continue; }
+        };
+
+        let value = match kind {
+            ValueKind::Password if !include_passwords => { continue; },
+            ValueKind::Text | ValueKind::Password | ValueKind::Choice => unsafe { wh::get_window_text(handle) },
+            ValueKind::Check => match wh::send_message(handle, BM_GETCHECK, 0, 0) as usize {
+                BST_CHECKED => "true".to_string(),
+                BST_INDETERMINATE => "indeterminate".to_string(),
+                _ => "false".to_string(),
+            }
+        };
+
+        values.insert(name.to_string(), value);
+    }
+
+    values
+}
+
+/**
+    Restore the values saved by `snapshot_values`. Each control is given with the name used when the values were saved.
+    Names that are not in `values` and controls that are not input controls are ignored.
+    A combo box value that is not in the combo box collection clears the selection.
+
+    Events are not raised when the values are restored (see `with_events_suppressed`).
+
+    This function panics if one of the controls is not a window-like control.
+*/
+pub fn restore_values(controls: &[(&str, ControlHandle)], values: &HashMap<String, String>) {
+    use winapi::um::winuser::{BM_SETCHECK, BST_CHECKED, BST_INDETERMINATE, BST_UNCHECKED, CB_FINDSTRINGEXACT, CB_SETCURSEL};
+    use winapi::shared::minwindef::WPARAM;
+    use crate::controls::WideBuffer;
+    use super::window::with_events_suppressed;
+
+    for &(name, control) in controls {
+        let handle = control.hwnd().expect("restore_values only works with window-like controls");
+        let (value, kind) = match (values.get(name), value_kind(handle)) {
+            (Some(v), Some(k)) => (v, k),
+            _ => { continue; }
+        };
+
+        with_events_suppressed(control, || match kind {
+            ValueKind::Text | ValueKind::Password => unsafe { wh::set_window_text(handle, value) },
+            ValueKind::Choice => {
+                let mut value_os = WideBuffer::from_str(value);
+                let index = wh::send_message(handle, CB_FINDSTRINGEXACT, usize::max_value(), value_os.as_lparam());
+                wh::send_message(handle, CB_SETCURSEL, index as WPARAM, 0);
+            },
+            ValueKind::Check => {
+                let state = match value.as_str() {
+                    "true" => BST_CHECKED,
+                    "indeterminate" => BST_INDETERMINATE,
+                    _ => BST_UNCHECKED,
+                };
+                wh::send_message(handle, BM_SETCHECK, state as WPARAM, 0);
+            }
+        });
+    }
+}
+
+fn value_kind(handle: HWND) -> Option<ValueKind> {
+    use winapi::um::winuser::{ES_PASSWORD, BS_TYPEMASK, BS_CHECKBOX, BS_AUTOCHECKBOX, BS_3STATE, BS_AUTO3STATE, BS_RADIOBUTTON, BS_AUTORADIOBUTTON};
+
+    let class_name = unsafe { wh::get_window_class_name(handle) };
+    let style = wh::get_style(handle);
+
+    match class_name.to_uppercase().as_str() {
+        "EDIT" if style & ES_PASSWORD == ES_PASSWORD => Some(ValueKind::Password),
+        "EDIT" | "RICHEDIT50W" => Some(ValueKind::Text),
+        "COMBOBOX" => Some(ValueKind::Choice),
+        "BUTTON" => match style & BS_TYPEMASK {
+            BS_CHECKBOX | BS_AUTOCHECKBOX | BS_3STATE | BS_AUTO3STATE | BS_RADIOBUTTON | BS_AUTORADIOBUTTON => Some(ValueKind::Check),
+            _ => None
+        },
+        _ => None
+    }
+}
@@ -0,0 +1,153 @@
+use winapi::shared::windef::{HWND, HBRUSH};
+use winapi::shared::minwindef::{UINT, DWORD};
+use winapi::shared::basetsd::UINT_PTR;
+use super::window::RawEventHandler;
+use super::window_helper as wh;
+use std::collections::HashMap;
+use std::cell::RefCell;
+
+/// Id of the raw handler bound on the parent of the highlighted controls
+const HIGHLIGHT_HANDLER_ID: UINT_PTR = 0xFFF0;
+
+/// Id of the timer that removes the highlight
+const HIGHLIGHT_TIMER_ID: UINT_PTR = 0xFFF0;
+
+struct Highlight {
+    parent: HWND,
+    color: [u8; 3],
+    brush: HBRUSH,
+}
+
+#[derive(Default)]
+struct Highlights {
+    controls: HashMap<HWND, Highlight>,
+    parents: HashMap<HWND, RawEventHandler>,
+}
+
+thread_local! {
+    static HIGHLIGHTS: RefCell<Highlights> = RefCell::new(Highlights::default());
+}
+
+/// Paint the background of a control with `color` for `duration` milliseconds. Replace the current highlight if there is one.
+pub fn highlight(handle: HWND, color: [u8; 3], duration: u32) {
+    use winapi::um::winuser::SetTimer;
+    use winapi::um::wingdi::{CreateSolidBrush, RGB};
+
+    remove_destroyed();
+    clear_highlight(handle);
+
+    let parent = wh::get_window_parent(handle);
+    let brush = unsafe { CreateSolidBrush(RGB(color[0], color[1], color[2])) };
+
+    HIGHLIGHTS.with(|highlights| {
+        let mut highlights = highlights.borrow_mut();
+        highlights.controls.insert(handle, Highlight { parent, color, brush });
+
+        if !highlights.parents.contains_key(&parent) {
+            highlights.parents.insert(parent, bind_parent_handler(parent));
+        }
+    });
+
+    unsafe {
+        SetTimer(handle, HIGHLIGHT_TIMER_ID, duration, Some(highlight_timer));
+        redraw(handle);
+    }
+}
+
+/// Remove the highlight of a control. Return `false` if the control was not highlighted.
+pub fn clear_highlight(handle: HWND) -> bool {
+    use winapi::um::winuser::{KillTimer, IsWindow};
+    use winapi::um::wingdi::DeleteObject;
+    use winapi::shared::windef::HGDIOBJ;
+
+    let removed = HIGHLIGHTS.with(|highlights| {
+        let mut highlights = highlights.borrow_mut();
+        let highlight = highlights.controls.remove(&handle)?;
+
+        // The parent handler is removed with the last highlighted child
+        let parent = highlight.parent;
+        let handler = match highlights.controls.values().any(|h| h.parent == parent) {
+            true => None,
+            false => highlights.parents.remove(&parent)
+        };
+
+        Some((highlight, handler))
+    });
+
+    let (highlight, handler) = match removed {
+        Some(r) => r,
+        None => { return false; }
+    };
+
+    unsafe {
+        if let Some(handler) = handler {
+            if IsWindow(highlight.parent) != 0 {
+                drop(crate::unbind_raw_event_handler(&handler));
+            }
+        }
+
+        DeleteObject(highlight.brush as HGDIOBJ);
+
+        if IsWindow(handle) != 0 {
+            KillTimer(handle, HIGHLIGHT_TIMER_ID);
+            redraw(handle);
+        }
+    }
+
+    true
+}
+
+/// Free the highlights of the controls that were destroyed before their timer expired
+fn remove_destroyed() {
+    use winapi::um::winuser::IsWindow;
+
+    let destroyed: Vec<HWND> = HIGHLIGHTS.with(|highlights| {
+        highlights.borrow().controls.keys()
+            .filter(|&&h| unsafe { IsWindow(h) == 0 })
+            .copied()
+            .collect()
+    });
+
+    for handle in destroyed {
+        clear_highlight(handle);
+    }
+}
+
+/// The parent of a control chooses its background color with the `WM_CTLCOLOR*` messages
+fn bind_parent_handler(parent: HWND) -> RawEventHandler {
+    use crate::{bind_raw_event_handler_inner, ControlHandle};
+    use winapi::um::winuser::{WM_CTLCOLOREDIT, WM_CTLCOLORSTATIC, WM_CTLCOLORLISTBOX, WM_CTLCOLORBTN};
+    use winapi::um::wingdi::{SetBkColor, RGB};
+    use winapi::shared::windef::HDC;
+    use winapi::shared::minwindef::LRESULT;
+
+    let handler = bind_raw_event_handler_inner(&ControlHandle::Hwnd(parent), HIGHLIGHT_HANDLER_ID, move |_hwnd, msg, w, l| {
+        match msg {
+            WM_CTLCOLOREDIT | WM_CTLCOLORSTATIC | WM_CTLCOLORLISTBOX | WM_CTLCOLORBTN => {
+                let child = l as HWND;
+                let highlight = HIGHLIGHTS.with(|highlights| {
+                    let highlights = highlights.try_borrow().ok()?;
+                    highlights.controls.get(&child).map(|h| (h.color, h.brush))
+                });
+
+                let (c, brush) = highlight?;
+                unsafe { SetBkColor(w as HDC, RGB(c[0], c[1], c[2])); }
+                Some(brush as LRESULT)
+            },
+            _ => None
+        }
+    });
+
+    handler.unwrap()
+}
+
+unsafe fn redraw(handle: HWND) {
+    use winapi::um::winuser::{RedrawWindow, RDW_INVALIDATE, RDW_ERASE, RDW_FRAME, RDW_ALLCHILDREN};
+    use std::ptr;
+
+    RedrawWindow(handle, ptr::null(), ptr::null_mut(), RDW_INVALIDATE | RDW_ERASE | RDW_FRAME | RDW_ALLCHILDREN);
+}
+
+unsafe extern "system" fn highlight_timer(handle: HWND, _msg: UINT, _id: UINT_PTR, _time: DWORD) {
+    clear_highlight(handle);
+}
@@ -0,0 +1,205 @@
+use crate::controls::ControlHandle;
+use winapi::shared::windef::HWND;
+use std::ptr;
+
+
+/**
+    Ask the user to enter a line of text in an application wide dialog.
+    It is recommended to use `modal_input_box` because it locks the window that creates the dialog.
+
+    Parameters:
+    * title: The dialog title
+    * prompt: The text displayed over the text input
+    * default: The initial value of the text input
+
+    Returns the text entered by the user, or `None` if the dialog was cancelled.
+    Like in the standard dialogs, OK is the default button and Cancel the cancel button: ENTER validates the dialog,
+    ESCAPE cancels it, and ENTER presses the button that has the keyboard focus.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn ask_name() -> Option<String> {
+        nwg::input_box("Hey", "What is your name?", "")
+    }
+    ```
+*/
+pub fn input_box<'a>(title: &'a str, prompt: &'a str, default: &'a str) -> Option<String> {
+    inner_input_box(ptr::null_mut(), title, prompt, default)
+}
+
+/**
+    Ask the user to enter a line of text. The parent window will be locked until the user closes the dialog.
+
+    This functions panics if a non window control is used as parent (ex: a menu)
+
+    Parameters:
+    * parent: The reference to a window-like control
+    * title: The dialog title
+    * prompt: The text displayed over the text input
+    * default: The initial value of the text input
+
+    Returns the text entered by the user, or `None` if the dialog was cancelled.
+    Like in the standard dialogs, OK is the default button and Cancel the cancel button: ENTER validates the dialog,
+    ESCAPE cancels it, and ENTER presses the button that has the keyboard focus.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn ask_name(parent: &nwg::Window) -> Option<String> {
+        nwg::modal_input_box(parent, "Hey", "What is your name?", "")
+    }
+    ```
+*/
+pub fn modal_input_box<'a, P: Into<ControlHandle>>(parent: P, title: &'a str, prompt: &'a str, default: &'a str) -> Option<String> {
+    let control_handle = parent.into();
+    let hwnd = control_handle.hwnd().expect("expected window like control");
+    inner_input_box(hwnd, title, prompt, default)
+}
+
+fn inner_input_box(parent: HWND, title: &str, prompt: &str, default: &str) -> Option<String> {
+    use crate::{Window, WindowFlags, Label, TextInput, Button, Event, EventData};
+    use crate::{full_bind_event_handler, unbind_event_handler, bind_raw_event_handler_inner, unbind_raw_event_handler};
+    use super::window_helper as wh;
+    use winapi::um::winuser::{GetMessageW, PostQuitMessage, EnableWindow, SetWindowLongPtrW, MSG, GWLP_ID, IDOK, IDCANCEL, BS_DEFPUSHBUTTON,
+      DM_GETDEFID, DC_HASDEFID};
+    use winapi::shared::minwindef::MAKELONG;
+    use std::{mem, rc::Rc, cell::{Cell, RefCell}};
+
+    let parent_handle = match parent.is_null() {
+        true => None,
+        false => Some(ControlHandle::Hwnd(parent))
+    };
+
+    let mut window = Window::default();
+    let mut label = Label::default();
+    let mut input = TextInput::default();
+    let mut ok = Button::default();
+    let mut cancel = Button::default();
+
+    Window::builder()
+        .flags(WindowFlags::WINDOW)
+        .size((350, 130))
+        .title(title)
+        .parent(parent_handle)
+        .build(&mut window)
+        .ok()?;
+
+    Label::builder()
+        .text(prompt)
+        .position((10, 10))
+        .size((330, 20))
+        .parent(&window)
+        .build(&mut label)
+        .ok()?;
+
+    TextInput::builder()
+        .text(default)
+        .position((10, 35))
+        .size((330, 25))
+        .parent(&window)
+        .build(&mut input)
+        .ok()?;
+
+    Button::builder()
+        .text("OK")
+        .position((185, 95))
+        .size((75, 25))
+        .parent(&window)
+        .build(&mut ok)
+        .ok()?;
+
+    Button::builder()
+        .text("Cancel")
+        .position((265, 95))
+        .size((75, 25))
+        .parent(&window)
+        .build(&mut cancel)
+        .ok()?;
+
+    // The dialog navigation (`IsDialogMessage`) sends ENTER to the default button and ESCAPE to the IDCANCEL button
+    unsafe {
+        let ok_handle = ok.handle.hwnd().unwrap();
+        wh::set_style(ok_handle, wh::get_style(ok_handle) | BS_DEFPUSHBUTTON);
+        SetWindowLongPtrW(ok_handle, GWLP_ID, IDOK as _);
+        SetWindowLongPtrW(cancel.handle.hwnd().unwrap(), GWLP_ID, IDCANCEL as _);
+    }
+
+    // Like in the standard dialogs, the focused button is the default button
+    let default_handler = {
+        let dialog = window.handle.clone();
+        let cancel = cancel.handle.clone();
+        bind_raw_event_handler_inner(&window.handle, 0, move |_hwnd, msg, _w, _l| {
+            if msg != DM_GETDEFID {
+                return None;
+            }
+
+            let id = match dialog.focused_child() == Some(cancel) {
+                true => IDCANCEL,
+                false => IDOK
+            };
+
+            Some(MAKELONG(id as u16, DC_HASDEFID as u16) as _)
+        }).ok()?
+    };
+
+    let result: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+    let done = Rc::new(Cell::new(false));
+
+    let handler = {
+        let result = result.clone();
+        let done = done.clone();
+        let input = input.handle.clone();
+        let ok = ok.handle.clone();
+        let cancel = cancel.handle.clone();
+
+        full_bind_event_handler(&window.handle, move |evt, evt_data, handle| {
+            let validate = match (evt, &evt_data) {
+                (Event::OnButtonClick, _) if handle == ok => true,
+                (Event::OnButtonClick, _) if handle == cancel => false,
+                (Event::OnWindowClose, EventData::OnWindowClose(data)) => {
+                    data.close(false);
+                    false
+                },
+                _ => { return; }
+            };
+
+            if validate {
+                *result.borrow_mut() = Some(input.text());
+            }
+
+            done.set(true);
+        })
+    };
+
+    window.center_on_parent();
+    window.set_visible(true);
+    input.set_focus();
+    input.set_selection(0..input.len());
+
+    unsafe {
+        if !parent.is_null() {
+            EnableWindow(parent, 0);
+        }
+
+        let mut msg: MSG = mem::zeroed();
+        while !done.get() {
+            if GetMessageW(&mut msg, ptr::null_mut(), 0, 0) <= 0 {
+                // Forward the quit message to the application event loop
+                PostQuitMessage(msg.wParam as i32);
+                break;
+            }
+
+            super::dispatch_message(&mut msg);
+        }
+
+        // The parent must be enabled before the dialog is destroyed, otherwise another application gets activated
+        if !parent.is_null() {
+            EnableWindow(parent, 1);
+        }
+    }
+
+    unbind_event_handler(&handler);
+    drop(unbind_raw_event_handler(&default_handler));
+
+    let value = result.borrow_mut().take();
+    value
+}
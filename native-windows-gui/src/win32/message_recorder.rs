@@ -0,0 +1,95 @@
+use crate::controls::ControlHandle;
+use crate::win32::window::RawEventHandler;
+use crate::NwgError;
+use winapi::shared::windef::HWND;
+use std::{rc::Rc, cell::RefCell};
+
+/// Id of the raw handler used by the recorder
+const RECORDER_HANDLER_ID: usize = 0xFFF1;
+
+/// A message received by a control
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedMessage {
+    pub msg: u32,
+    pub w: usize,
+    pub l: isize,
+}
+
+/**
+    Records the messages received by a control. Only available with the `message-recorder` feature.
+
+    This is meant to be used in tests to check the message flow of a control. The recorder does not handle the messages,
+    they are still processed by the control and by the event handlers. Messages are recorded until the recorder is dropped.
+
+    Note that the notifications of a control (ex: `WM_COMMAND` with `BN_CLICKED`) are sent to its parent, so the parent must be recorded.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use winapi::um::winuser::{BM_CLICK, WM_COMMAND};
+
+    fn test_click(window: &nwg::Window, button: &nwg::Button) {
+        let recorder = nwg::MessageRecorder::new(&window.handle).unwrap();
+        unsafe { button.handle.send_message(BM_CLICK, 0, 0); }
+        assert!(recorder.contains(WM_COMMAND));
+    }
+    ```
+*/
+pub struct MessageRecorder {
+    handle: HWND,
+    handler: Option<RawEventHandler>,
+    messages: Rc<RefCell<Vec<RecordedMessage>>>,
+}
+
+impl MessageRecorder {
+
+    /// Start recording the messages received by `handle`.
+    /// Returns an error if the handle is not a window handle or if a recorder is already bound to the control.
+    pub fn new(handle: &ControlHandle) -> Result<MessageRecorder, NwgError> {
+        use crate::bind_raw_event_handler_inner;
+
+        let hwnd = handle.hwnd().ok_or_else(|| NwgError::events_binding("MessageRecorder only works with window handles"))?;
+        let messages: Rc<RefCell<Vec<RecordedMessage>>> = Rc::new(RefCell::new(Vec::new()));
+
+        let handler = {
+            let messages = messages.clone();
+            bind_raw_event_handler_inner(handle, RECORDER_HANDLER_ID, move |_hwnd, msg, w, l| {
+                // A message can be sent while the recorded messages are being read
+                if let Ok(mut messages) = messages.try_borrow_mut() {
+                    messages.push(RecordedMessage { msg, w, l });
+                }
+
+                None
+            })?
+        };
+
+        Ok(MessageRecorder { handle: hwnd, handler: Some(handler), messages })
+    }
+
+    /// Return a copy of the messages received since the recorder was created or cleared
+    pub fn messages(&self) -> Vec<RecordedMessage> {
+        self.messages.borrow().clone()
+    }
+
+    /// Return `true` if a message with the id `msg` was received
+    pub fn contains(&self, msg: u32) -> bool {
+        self.messages.borrow().iter().any(|m| m.msg == msg)
+    }
+
+    /// Remove the recorded messages
+    pub fn clear(&self) {
+        self.messages.borrow_mut().clear();
+    }
+
+}
+
+impl Drop for MessageRecorder {
+    fn drop(&mut self) {
+        use winapi::um::winuser::IsWindow;
+
+        if let Some(h) = self.handler.take() {
+            if unsafe { IsWindow(self.handle) } != 0 {
+                drop(crate::unbind_raw_event_handler(&h));
+            }
+        }
+    }
+}
@@ -3,7 +3,13 @@ pub(crate) mod window_helper;
 pub(crate) mod resources_helper;
 pub(crate) mod window;
 pub(crate) mod message_box;
+pub(crate) mod input_box;
+pub(crate) mod toast;
+pub(crate) mod form_values;
+pub(crate) mod highlight;
 pub(crate) mod high_dpi;
+pub(crate) mod accessibility;
+pub(crate) mod monitor;
 
 #[cfg(feature = "menu")]
 pub(crate) mod menu;
@@ -26,26 +32,62 @@ pub(crate) mod image_decoder;
 #[cfg(feature = "rich-textbox")]
 pub(crate) mod richedit;
 
+#[cfg(feature = "message-recorder")]
+pub(crate) mod message_recorder;
+
 use std::{mem, ptr};
 use crate::errors::NwgError;
 
 
-use winapi::um::winuser::{IsDialogMessageW, GetAncestor, TranslateMessage, DispatchMessageW, GA_ROOT};
+use winapi::shared::windef::HWND;
+use winapi::um::winuser::{IsDialogMessageW, GetAncestor, TranslateMessage, DispatchMessageW, GA_ROOT, MSG};
+
+/// Translate and dispatch a message. Keyboard navigation messages are handled by `IsDialogMessageW`
+/// unless the top level window opted out with `Window::set_dialog_navigation`
+unsafe fn dispatch_message(msg: &mut MSG) {
+    let root = GetAncestor(msg.hwnd, GA_ROOT);
+    if translate_shortcut(root, msg) {
+        return;
+    }
+
+    if !window_helper::get_dialog_navigation(root) || IsDialogMessageW(root, msg) == 0 {
+        TranslateMessage(msg); 
+        DispatchMessageW(msg); 
+    }
+}
+
+/// Send `NWG_SHORTCUT` to the top level window if the message is a key press matching one of its shortcuts.
+/// Return `true` if the message was consumed. Other key presses are left to the focused control.
+unsafe fn translate_shortcut(root: HWND, msg: &MSG) -> bool {
+    use winapi::um::winuser::{WM_KEYDOWN, WM_SYSKEYDOWN, SendMessageW};
+    use winapi::shared::minwindef::WPARAM;
+
+    if msg.message != WM_KEYDOWN && msg.message != WM_SYSKEYDOWN {
+        return false;
+    }
+
+    match window_helper::find_shortcut(root, key_modifiers().bits(), msg.wParam as u32) {
+        Some(id) => {
+            // Auto repeated key presses are consumed but do not raise the event again
+            if msg.lParam & (1 << 30) == 0 {
+                SendMessageW(root, window_helper::NWG_SHORTCUT, id as WPARAM, 0);
+            }
+            true
+        },
+        None => false
+    }
+}
 
 /**
     Dispatch system events in the current thread. This method will pause the thread until there are events to process.
 */
 pub fn dispatch_thread_events() {
-    use winapi::um::winuser::MSG;
     use winapi::um::winuser::GetMessageW;
 
     unsafe {
         let mut msg: MSG = mem::zeroed();
         while GetMessageW(&mut msg, ptr::null_mut(), 0, 0) != 0 {
-            if IsDialogMessageW(GetAncestor(msg.hwnd, GA_ROOT), &mut msg) == 0 {
-                TranslateMessage(&msg); 
-                DispatchMessageW(&msg); 
-            }
+            dispatch_message(&mut msg);
         }
     }
 }
@@ -58,7 +100,6 @@ pub fn dispatch_thread_events() {
 pub fn dispatch_thread_events_with_callback<F>(mut cb: F) 
     where F: FnMut() -> () + 'static
 {
-    use winapi::um::winuser::MSG;
     use winapi::um::winuser::{PeekMessageW, PM_REMOVE, WM_QUIT};
 
     unsafe {
@@ -66,10 +107,7 @@ pub fn dispatch_thread_events_with_callback<F>(mut cb: F)
         while msg.message != WM_QUIT {
             let has_message = PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) != 0;
             if has_message {
-                if IsDialogMessageW(GetAncestor(msg.hwnd, GA_ROOT), &mut msg) == 0 {
-                    TranslateMessage(&msg); 
-                    DispatchMessageW(&msg); 
-                }
+                dispatch_message(&mut msg);
             }
 
             cb();
@@ -77,6 +115,33 @@ pub fn dispatch_thread_events_with_callback<F>(mut cb: F)
     }
 }
 
+/**
+    Return `true` if the key was held down when the message being processed was sent. `key` is a virtual key code from the `nwg::keys` module.
+
+    This can be used in any event handler, ex: to check if SHIFT was held during a `OnListBoxSelect`.
+    The state is synchronized with the message queue (`GetKeyState`): it is the state of the keyboard when the event was raised,
+    not when the handler runs. Use `GetAsyncKeyState` from winapi to read the physical state of the keyboard instead.
+*/
+pub fn key_pressed(key: u32) -> bool {
+    use winapi::um::winuser::GetKeyState;
+    unsafe { GetKeyState(key as i32) < 0 }
+}
+
+/**
+    Return the modifier keys held down when the message being processed was sent. See `key_pressed`.
+*/
+pub fn key_modifiers() -> crate::KeyModifiers {
+    use winapi::um::winuser::{VK_CONTROL, VK_SHIFT, VK_MENU};
+    use crate::KeyModifiers;
+
+    let mut modifiers = KeyModifiers::empty();
+    if key_pressed(VK_CONTROL as u32) { modifiers |= KeyModifiers::CONTROL; }
+    if key_pressed(VK_SHIFT as u32) { modifiers |= KeyModifiers::SHIFT; }
+    if key_pressed(VK_MENU as u32) { modifiers |= KeyModifiers::ALT; }
+
+    modifiers
+}
+
 /**
     Break the events loop running on the current thread
 */
@@ -137,11 +202,11 @@ pub fn init_common_controls() -> Result<(), NwgError> {
     use winapi::um::libloaderapi::LoadLibraryW;
     use winapi::um::commctrl::{InitCommonControlsEx, INITCOMMONCONTROLSEX};
     use winapi::um::commctrl::{ICC_BAR_CLASSES, ICC_STANDARD_CLASSES, ICC_DATE_CLASSES, ICC_PROGRESS_CLASS,
-     ICC_TAB_CLASSES, ICC_TREEVIEW_CLASSES, ICC_LISTVIEW_CLASSES};
+     ICC_TAB_CLASSES, ICC_TREEVIEW_CLASSES, ICC_LISTVIEW_CLASSES, ICC_UPDOWN_CLASS};
     use winapi::shared::winerror::{S_OK, S_FALSE};
 
     unsafe {
-        let mut classes = ICC_BAR_CLASSES | ICC_STANDARD_CLASSES;
+        let mut classes = ICC_BAR_CLASSES | ICC_STANDARD_CLASSES | ICC_UPDOWN_CLASS;
 
         if cfg!(feature = "datetime-picker") {
             classes |= ICC_DATE_CLASSES;
@@ -180,6 +245,7 @@ pub fn init_common_controls() -> Result<(), NwgError> {
     tabs_init()?;
     extern_canvas_init()?;
     frame_init()?;
+    splitter_init()?;
     
     match unsafe { CoInitialize(ptr::null_mut()) } {
         S_OK | S_FALSE => Ok(()),
@@ -205,3 +271,9 @@ fn frame_init() -> Result<(), NwgError> { window::create_frame_classes() }
 #[cfg(not(feature = "frame"))]
 fn frame_init() -> Result<(), NwgError> { Ok(()) }
 
+#[cfg(feature = "splitter")]
+fn splitter_init() -> Result<(), NwgError> { window::create_splitter_classes() }
+
+#[cfg(not(feature = "splitter"))]
+fn splitter_init() -> Result<(), NwgError> { Ok(()) }
+
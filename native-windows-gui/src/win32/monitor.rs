@@ -0,0 +1,134 @@
+use winapi::shared::windef::{HMONITOR, HDC, LPRECT, RECT};
+use winapi::shared::minwindef::{BOOL, LPARAM, TRUE};
+use crate::controls::ControlHandle;
+use super::high_dpi;
+use std::{mem, ptr};
+
+
+/**
+    The information of a monitor returned by `Monitor`. Rects are `[x, y, width, height]` in logical coordinates,
+    the same coordinates used by the `position` and the `size` of the windows.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MonitorInfo {
+    /// The position and the size of the whole monitor
+    pub bounds: [i32; 4],
+
+    /// The position and the size of the monitor without the taskbar and the docked toolbars
+    pub work_area: [i32; 4],
+
+    /// `true` if this is the primary monitor
+    pub primary: bool,
+}
+
+/**
+    A global object to query the monitors of the system.
+    This object must not be instanced. The methods should be used this way:
+
+    ```rust
+    use native_windows_gui as nwg;
+    let monitors = nwg::Monitor::all();
+    let primary = monitors.iter().find(|m| m.primary);
+    ```
+
+    Monitors can be missing (ex: in some remote sessions), so every method handles an empty monitor list.
+*/
+pub struct Monitor;
+
+impl Monitor {
+
+    /// Return the information of every monitor of the system. The list is empty if there are no monitors.
+    pub fn all() -> Vec<MonitorInfo> {
+        use winapi::um::winuser::EnumDisplayMonitors;
+
+        let mut handles: Vec<HMONITOR> = Vec::new();
+        unsafe {
+            EnumDisplayMonitors(ptr::null_mut(), ptr::null(), Some(enum_monitor), &mut handles as *mut Vec<HMONITOR> as LPARAM);
+        }
+
+        handles.into_iter().filter_map(|m| unsafe { monitor_info(m) }).collect()
+    }
+
+    /// Return the monitor that has the largest area of intersection with a window.
+    /// If the window is not on any monitor, return the nearest one. Return `None` if there are no monitors.
+    /// Panics if the control is not a window.
+    pub fn from_window<C: Into<ControlHandle>>(control: C) -> Option<MonitorInfo> {
+        use winapi::um::winuser::{MonitorFromWindow, MONITOR_DEFAULTTONEAREST};
+
+        let handle = control.into().hwnd().expect("Monitor::from_window only works with window controls");
+        unsafe { monitor_info(MonitorFromWindow(handle, MONITOR_DEFAULTTONEAREST)) }
+    }
+
+    /// Return the monitor that contains a point, or the nearest one if the point is not on any monitor.
+    /// Return `None` if there are no monitors.
+    pub fn from_point(x: i32, y: i32) -> Option<MonitorInfo> {
+        use winapi::um::winuser::{MonitorFromPoint, MONITOR_DEFAULTTONEAREST};
+        use winapi::shared::windef::POINT;
+
+        unsafe {
+            let (x, y) = high_dpi::logical_to_physical(x, y);
+            monitor_info(MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONEAREST))
+        }
+    }
+
+    /**
+        Move a rect (`[x, y, width, height]`) so that it is fully visible in the work area of the nearest monitor.
+        If the rect is larger than the work area, its upper left corner is aligned with the work area. The size is never changed.
+
+        Ex: to restore a window to saved coordinates after a monitor was unplugged. If there are no monitors, the rect is returned as is.
+    */
+    pub fn clamp_rect(rect: [i32; 4]) -> [i32; 4] {
+        use winapi::um::winuser::{MonitorFromRect, MONITOR_DEFAULTTONEAREST};
+
+        let [x, y, width, height] = rect;
+
+        let info = unsafe {
+            let (left, top) = high_dpi::logical_to_physical(x, y);
+            let (right, bottom) = high_dpi::logical_to_physical(x + width, y + height);
+            monitor_info(MonitorFromRect(&RECT { left, top, right, bottom }, MONITOR_DEFAULTTONEAREST))
+        };
+
+        match info {
+            Some(info) => {
+                let [wx, wy, ww, wh] = info.work_area;
+                let x = x.min(wx + ww - width).max(wx);
+                let y = y.min(wy + wh - height).max(wy);
+                [x, y, width, height]
+            },
+            None => rect
+        }
+    }
+
+}
+
+unsafe extern "system" fn enum_monitor(monitor: HMONITOR, _dc: HDC, _rect: LPRECT, data: LPARAM) -> BOOL {
+    let handles = &mut *(data as *mut Vec<HMONITOR>);
+    handles.push(monitor);
+    TRUE
+}
+
+unsafe fn monitor_info(monitor: HMONITOR) -> Option<MonitorInfo> {
+    use winapi::um::winuser::{GetMonitorInfoW, MONITORINFO, MONITORINFOF_PRIMARY};
+
+    if monitor.is_null() {
+        return None;
+    }
+
+    let mut info: MONITORINFO = mem::zeroed();
+    info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if GetMonitorInfoW(monitor, &mut info) == 0 {
+        return None;
+    }
+
+    Some(MonitorInfo {
+        bounds: logical_rect(&info.rcMonitor),
+        work_area: logical_rect(&info.rcWork),
+        primary: info.dwFlags & MONITORINFOF_PRIMARY == MONITORINFOF_PRIMARY,
+    })
+}
+
+unsafe fn logical_rect(r: &RECT) -> [i32; 4] {
+    let (x, y) = high_dpi::physical_to_logical(r.left, r.top);
+    let (width, height) = high_dpi::physical_to_logical(r.right - r.left, r.bottom - r.top);
+    [x, y, width, height]
+}
@@ -15,6 +15,10 @@ use std::{mem, ptr};
 use std::convert::TryFrom;
 
 pub const EM_SETBKGNDCOLOR: u32 = WM_USER + 67;
+pub const EM_GETEVENTMASK: u32 = WM_USER + 59;
+pub const EM_SETEVENTMASK: u32 = WM_USER + 69;
+pub const ENM_SELCHANGE: u32 = 0x00080000;
+pub const EN_SELCHANGE: u32 = 0x0702;
 
 const EM_GETCHARFORMAT: u32 = WM_USER + 58;
 const EM_GETPARAFORMAT: u32 = WM_USER + 61;
@@ -0,0 +1,160 @@
+use crate::controls::{Window, Label};
+use crate::NwgError;
+use winapi::shared::windef::HWND;
+use std::cell::RefCell;
+
+const TOAST_TIMER: usize = 1;
+const TOAST_SIZE: (i32, i32) = (250, 40);
+const TOAST_MARGIN: i32 = 10;
+const TOAST_ALPHA: u8 = 220;
+
+/// A toast currently on screen. Dropping the value destroys the toast window.
+struct Toast {
+    window: Window,
+    _label: Label,
+    slot: i32,
+}
+
+thread_local! {
+    static TOASTS: RefCell<Vec<Toast>> = RefCell::new(Vec::new());
+}
+
+/**
+    Display a short message in a small borderless window near the bottom right corner of the active window.
+    The message is dismissed automatically after `duration` milliseconds. It never takes the focus and cannot be clicked.
+
+    Use this for transient notifications (ex: "Saved") that would be annoying in a message box.
+    If multiple toasts are displayed at the same time, they are stacked upward. If the application is not active,
+    the toast is displayed in the bottom right corner of the monitor under the cursor.
+
+    The toast window is destroyed when its timer fires. Toasts are bound to the thread that created them,
+    so this thread must keep processing messages for the toasts to be dismissed.
+
+    Parameters:
+    * text: The message to display
+    * duration: The time, in milliseconds, before the toast is dismissed
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn saved() {
+        nwg::toast("File saved", 2000).expect("Failed to display the toast");
+    }
+    ```
+*/
+pub fn toast<'a>(text: &'a str, duration: u32) -> Result<(), NwgError> {
+    use crate::{WindowFlags, HTextAlign, VTextAlign};
+    use winapi::um::winuser::{SetLayeredWindowAttributes, SetTimer, ShowWindow, LWA_ALPHA, SW_SHOWNOACTIVATE};
+    use winapi::um::winuser::{WS_EX_TOOLWINDOW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TRANSPARENT};
+
+    let (width, height) = TOAST_SIZE;
+    let slot = free_slot();
+
+    let mut window = Window::default();
+    let mut label = Label::default();
+
+    Window::builder()
+        .flags(WindowFlags::POPUP)
+        .ex_flags(WS_EX_TOOLWINDOW | WS_EX_LAYERED | WS_EX_NOACTIVATE | WS_EX_TRANSPARENT)
+        .topmost(true)
+        .size((width, height))
+        .title("")
+        .build(&mut window)?;
+
+    Label::builder()
+        .text(text)
+        .position((0, 0))
+        .size((width, height))
+        .h_align(HTextAlign::Center)
+        .v_align(VTextAlign::Center)
+        .parent(&window)
+        .build(&mut label)?;
+
+    let (right, bottom) = unsafe { toast_anchor() };
+    window.set_position(
+        right - width - TOAST_MARGIN,
+        bottom - (height + TOAST_MARGIN) * (slot + 1)
+    );
+
+    let hwnd = window.handle.hwnd().unwrap();
+
+    // The timer uses a callback instead of an event handler, because a window cannot be destroyed from its own handler
+    unsafe {
+        SetLayeredWindowAttributes(hwnd, 0, TOAST_ALPHA, LWA_ALPHA);
+        SetTimer(hwnd, TOAST_TIMER, duration, Some(dismiss_toast));
+        ShowWindow(hwnd, SW_SHOWNOACTIVATE);
+    }
+
+    let toast = Toast { window, _label: label, slot };
+    TOASTS.with(|toasts| toasts.borrow_mut().push(toast));
+
+    Ok(())
+}
+
+/// Timer callback that destroys the toast window once its duration has elapsed
+unsafe extern "system" fn dismiss_toast(hwnd: HWND, _msg: u32, id: usize, _time: u32) {
+    use winapi::um::winuser::KillTimer;
+
+    let removed: Vec<Toast> = TOASTS.with(|toasts| {
+        match toasts.try_borrow_mut() {
+            Ok(mut toasts) => {
+                KillTimer(hwnd, id);
+                let (removed, kept) = toasts.drain(..).partition(|t| t.window.handle.hwnd() == Some(hwnd));
+                *toasts = kept;
+                removed
+            },
+            // The timer fires again if the toasts are in use
+            Err(_) => Vec::new()
+        }
+    });
+
+    // Toasts are dropped outside of the borrow because destroying a window dispatches messages
+    drop(removed);
+}
+
+/// Return the lowest position in the stack that is not used by a toast
+fn free_slot() -> i32 {
+    TOASTS.with(|toasts| {
+        let toasts = toasts.borrow();
+        let mut slot = 0;
+        while toasts.iter().any(|t| t.slot == slot) {
+            slot += 1;
+        }
+
+        slot
+    })
+}
+
+/// Return the bottom right corner, in logical coordinates, of the application active window
+/// or the work area of the monitor under the cursor if the application is not active
+unsafe fn toast_anchor() -> (i32, i32) {
+    use winapi::um::winuser::{GetForegroundWindow, GetWindowThreadProcessId, GetWindowRect, GetCursorPos};
+    use winapi::um::processthreadsapi::GetCurrentProcessId;
+    use winapi::shared::windef::{RECT, POINT};
+    use super::high_dpi;
+    use crate::Monitor;
+    use std::mem;
+
+    let active = GetForegroundWindow();
+    if !active.is_null() {
+        let mut pid = 0;
+        GetWindowThreadProcessId(active, &mut pid);
+
+        if pid == GetCurrentProcessId() {
+            let mut r: RECT = mem::zeroed();
+            GetWindowRect(active, &mut r);
+            return high_dpi::physical_to_logical(r.right, r.bottom);
+        }
+    }
+
+    let mut p: POINT = mem::zeroed();
+    GetCursorPos(&mut p);
+    let (x, y) = high_dpi::physical_to_logical(p.x, p.y);
+
+    match Monitor::from_point(x, y) {
+        Some(info) => {
+            let [wx, wy, ww, wh] = info.work_area;
+            (wx + ww, wy + wh)
+        },
+        None => (x, y)
+    }
+}
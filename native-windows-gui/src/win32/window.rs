@@ -9,22 +9,32 @@ use winapi::shared::basetsd::{DWORD_PTR, UINT_PTR};
 use winapi::um::winuser::{WNDPROC, NMHDR};
 use winapi::um::commctrl::{NMTTDISPINFOW, SUBCLASSPROC};
 use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16};
-use super::window_helper::{NOTICE_MESSAGE, NWG_INIT, NWG_TRAY};
+use super::window_helper::{NOTICE_MESSAGE, NWG_INIT, NWG_TRAY, NWG_VALIDATION_FAILED, NWG_SHORTCUT, NWG_ITEM_MOVED, NWG_SUBMIT, NWG_DEBOUNCED, NWG_SYSTEM_CHANGED};
 use super::high_dpi;
 use crate::controls::ControlHandle;
 use crate::{Event, EventData, NwgError};
 use std::{ptr, mem};
 use std::rc::Rc;
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
 
 
 static mut TIMER_ID: u32 = 1; 
 static mut NOTICE_ID: u32 = 1; 
 static mut EVENT_HANDLER_ID: UINT_PTR = 1;
 
+/// Id of the timer used to merge the system changes notifications in a single `OnSystemColorsChanged` event
+const SYSTEM_CHANGES_TIMER: UINT_PTR = 0xFFF2;
+
+/// Delay in ms before `OnSystemColorsChanged` is raised after the last system changes notification
+const SYSTEM_CHANGES_DELAY: u32 = 100;
+
 const NO_DATA: EventData = EventData::NoData;
 
 type RawCallback = dyn Fn(HWND, UINT, WPARAM, LPARAM) -> Option<LRESULT>;
 type Callback = dyn Fn(Event, EventData, ControlHandle) -> ();
+type PanicHandler = dyn Fn(&(dyn Any + Send));
 
 /**
     An opaque structure that represent a window subclass hook. 
@@ -196,6 +206,47 @@ pub fn bind_event_handler<F>(handle: &ControlHandle, parent_handle: &ControlHand
     handler
 }
 
+/**
+Same as `bind_event_handler`, but `f` only receives the events of `handle` that are listed in `events`.
+
+Returns an error if one of the events is never raised for the control (see `ControlHandle::supported_events`).
+Ex: `OnTextInput` for a label. This catches handlers bound to the wrong event or to the wrong control.
+
+```rust
+use native_windows_gui as nwg;
+
+fn bind_click(button: &nwg::Button, window: &nwg::Window) -> Result<nwg::EventHandler, nwg::NwgError> {
+    nwg::bind_checked_event_handler(&button.handle, &window.handle, &[nwg::Event::OnButtonClick], |_evt, _evt_data, _handle| {
+        println!("Clicked!");
+    })
+}
+```
+*/
+pub fn bind_checked_event_handler<F>(handle: &ControlHandle, parent_handle: &ControlHandle, events: &[Event], f: F) -> Result<EventHandler, NwgError>
+    where F: Fn(Event, EventData, ControlHandle) -> () + 'static
+{
+    use std::mem::discriminant;
+
+    if handle.hwnd().is_none() || parent_handle.hwnd().is_none() {
+        return Err(NwgError::events_binding("bind_checked_event_handler only works with window handles"));
+    }
+
+    let unsupported: Vec<&Event> = events.iter().filter(|e| !handle.supports_event(**e)).collect();
+    if !unsupported.is_empty() {
+        return Err(NwgError::events_binding(format!("The control does not raise the events {:?}", unsupported)));
+    }
+
+    let control = *handle;
+    let events = events.to_vec();
+    let handler = bind_event_handler(handle, parent_handle, move |evt, evt_data, evt_handle| {
+        if evt_handle == control && events.iter().any(|e| discriminant(e) == discriminant(&evt)) {
+            f(evt, evt_data, evt_handle);
+        }
+    });
+
+    Ok(handler)
+}
+
 
 /**
     Free all associated callbacks with the event handler.
@@ -222,6 +273,9 @@ pub fn unbind_event_handler(handler: &EventHandler)
 
             RemoveWindowSubclass(handle, id, subclass_id);
         };
+
+        // The size is saved again before the next resize if another handler is still bound to the window
+        PREVIOUS_SIZES.with(|sizes| sizes.borrow_mut().remove(&(handle as usize)));
     }
 
     // Finally free the pointer to the pointer to the callback
@@ -263,6 +317,115 @@ pub(crate) fn bind_raw_event_handler_inner<F>(handle: &ControlHandle, handler_id
     })
 }
 
+/**
+    Set a function called when an event handler panics. The argument is the panic payload (see `std::panic::catch_unwind`).
+
+    Panics cannot unwind through the window procedures because they are called by the system. NWG catches the panics
+    raised by the event handlers, calls this function, and lets the system process the event as if it was not handled.
+    The panic message is still printed by the default panic hook. Pass `None` to remove the function.
+
+    The function is only called for the events handlers of the current thread. It can process messages
+    (ex: by showing a message box), even if another event handler panics in the meantime.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn exit_on_panic() {
+        nwg::set_event_panic_handler(Some(|_payload: &(dyn std::any::Any + Send)| {
+            nwg::stop_thread_dispatch();
+        }));
+    }
+    ```
+*/
+pub fn set_event_panic_handler<F>(handler: Option<F>)
+    where F: Fn(&(dyn Any + Send)) + 'static
+{
+    EVENT_PANIC_HANDLER.with(|panic_handler| {
+        *panic_handler.borrow_mut() = handler.map(|h| Rc::new(h) as Rc<PanicHandler>);
+    });
+}
+
+/// Execute an event handler and catch the panics so that they do not unwind in the system code
+fn catch_event_panic<R, F: FnOnce() -> R>(f: F) -> Option<R> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(r) => Some(r),
+        Err(payload) => {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                // The handler is cloned out so that it is not borrowed if it processes messages and another handler panics
+                let panic_handler = EVENT_PANIC_HANDLER.with(|panic_handler| panic_handler.borrow().clone());
+                if let Some(handler) = panic_handler {
+                    handler(&*payload);
+                }
+            }));
+
+            None
+        }
+    }
+}
+
+thread_local! {
+    /// Function called when an event handler panics. See `set_event_panic_handler`
+    static EVENT_PANIC_HANDLER: RefCell<Option<Rc<PanicHandler>>> = RefCell::new(None);
+
+    /// Client size of the windows before their last resize. Updated in `WM_WINDOWPOSCHANGING`, before the size changes.
+    /// Entries are removed when the window is destroyed or when an event handler is unbound from it.
+    static PREVIOUS_SIZES: RefCell<std::collections::HashMap<usize, [u32; 2]>> = RefCell::new(std::collections::HashMap::new());
+
+    /// System changes received by the top level windows that were not yet dispatched in a `OnSystemColorsChanged` event
+    static PENDING_SYSTEM_CHANGES: RefCell<std::collections::HashMap<usize, u32>> = RefCell::new(std::collections::HashMap::new());
+
+    /// Controls that currently do not receive events. A control can be added multiple times if calls are nested.
+    static SUPPRESSED_EVENTS: RefCell<Vec<ControlHandle>> = RefCell::new(Vec::new());
+}
+
+/// Removes a control from `SUPPRESSED_EVENTS` when dropped, even if the closure of `with_events_suppressed` panics.
+struct SuppressedEventsGuard(ControlHandle);
+
+impl Drop for SuppressedEventsGuard {
+    fn drop(&mut self) {
+        SUPPRESSED_EVENTS.with(|suppressed| {
+            let mut suppressed = suppressed.borrow_mut();
+            if let Some(index) = suppressed.iter().position(|&h| h == self.0) {
+                suppressed.remove(index);
+            }
+        });
+    }
+}
+
+/**
+    Execute `f` without dispatching the events of `control` to the event handlers. Returns the value returned by `f`.
+
+    This is used when the application updates a control and does not want its own event handlers to be called in return.
+    Ex: setting the text of a `TextInput` bound to a model without raising `OnTextInput`.
+
+    Only the events raised while `f` is executing are suppressed. Events that are posted by the system and
+    processed later by the event loop are still dispatched. Raw event handlers are never suppressed.
+    The control receives events again when `f` returns, even if it panics.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn update_name(input: &nwg::TextInput, name: &str) {
+        nwg::with_events_suppressed(input, || input.set_text(name));
+    }
+    ```
+*/
+pub fn with_events_suppressed<C, F, R>(control: C, f: F) -> R
+    where C: Into<ControlHandle>,
+          F: FnOnce() -> R
+{
+    let handle = control.into();
+    SUPPRESSED_EVENTS.with(|suppressed| suppressed.borrow_mut().push(handle));
+
+    let _guard = SuppressedEventsGuard(handle);
+    f()
+}
+
+/// Check if the events of a control are suppressed by `with_events_suppressed`
+fn events_suppressed(handle: &ControlHandle) -> bool {
+    SUPPRESSED_EVENTS.with(|suppressed| suppressed.borrow().contains(handle))
+}
+
 /**
 
 Set a window subclass the uses the `process_raw_events` function of NWG.
@@ -271,6 +434,13 @@ The subclass is only applied to the control itself and NOT the children.
 When assigning multiple callback to the same control, a different `id` must be specified for each call
 or otherwise, the old callback will be replaced by the new one. See `Label::hook_background_color` for example.
 
+The callback is called on the thread that owns the control, before the default window procedure. Returning `Some(result)`
+stops the processing of the message and `result` is returned to the system. Returning `None` lets the message go through
+the other handlers as usual. Subclasses are called from the most recent to the oldest, so a raw handler bound after
+`full_bind_event_handler` sees the messages before the NWG events are raised.
+
+The callback is a `Fn`. Use a `Cell` or a `RefCell` to keep a mutable state.
+
 Error:
 - If the event handler with the same ID is already bound, this function will return an Error. The `has_raw_handler` method can be used to check this.
 
@@ -413,6 +583,8 @@ pub(crate) unsafe fn build_hwnd_control<'a>(
     }
 }
 
+/// Register a window class. A class that is already registered in the process is reused
+/// (`ERROR_CLASS_ALREADY_EXISTS`), so this can be called from any thread every time a control that needs the class is initialized.
 pub(crate) unsafe fn build_sysclass<'a>(
     hmod: HMODULE,
     class_name: &'a str,
@@ -484,6 +656,21 @@ pub(crate) fn create_frame_classes() -> Result<(), NwgError> {
     Ok(())
 }
 
+#[cfg(feature = "splitter")]
+/// Create the window class for the splitter control
+pub(crate) fn create_splitter_classes() -> Result<(), NwgError> {
+    use winapi::um::libloaderapi::GetModuleHandleW;
+    
+    unsafe {
+        let hmod = GetModuleHandleW(ptr::null_mut());
+        if hmod.is_null() { return Err(NwgError::initialization("GetModuleHandleW failed")); }
+
+        build_sysclass(hmod, "NWG_SPLITTER", Some(blank_window_proc), None, None)?;
+    }
+    
+    Ok(())
+}
+
 #[cfg(feature = "message-window")]
 /// Create a message only window. Used with the `MessageWindow` control
 pub(crate) fn create_message_window() -> Result<ControlHandle, NwgError> {
@@ -525,8 +712,26 @@ pub(crate) fn create_message_window() -> Result<ControlHandle, NwgError> {
     A blank system procedure used when creating new window class. Actual system event handling is done in the subclass procedure `process_events`.
 */
 unsafe extern "system" fn blank_window_proc(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
-    use winapi::um::winuser::{WM_CREATE, WM_CLOSE, SW_HIDE};
-    use winapi::um::winuser::{DefWindowProcW, PostMessageW, ShowWindow};
+    use winapi::um::winuser::{WM_CREATE, WM_CLOSE, WM_NCHITTEST, WM_NCDESTROY, WM_SIZING, WS_THICKFRAME, SW_HIDE};
+    use winapi::um::winuser::{DefWindowProcW, PostMessageW, ShowWindow, GetWindowRect};
+    use winapi::shared::windef::RECT;
+
+    match msg {
+        WM_NCHITTEST => if let Some(hit) = super::window_helper::custom_frame_hit_test(hwnd, l) {
+            return hit;
+        },
+        WM_NCDESTROY => {
+            super::window_helper::set_custom_frame(hwnd, None, 0);
+            super::window_helper::set_dialog_navigation(hwnd, true);
+        },
+        // Windows without a resizable border keep their size while the user drags them (ex: `Window::set_resizable(false)`).
+        // A custom frame with a border is resized by its hit test instead.
+        WM_SIZING => if l != 0 && super::window_helper::get_style(hwnd) & WS_THICKFRAME == 0 && super::window_helper::get_custom_frame(hwnd).1 == 0 {
+            GetWindowRect(hwnd, l as *mut RECT);
+            return 1;
+        },
+        _ => {}
+    }
 
     let handled = match msg {
         WM_CREATE => {
@@ -562,13 +767,20 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
     use winapi::um::winuser::{WM_CLOSE, WM_COMMAND, WM_MENUCOMMAND, WM_TIMER, WM_NOTIFY, WM_HSCROLL, WM_VSCROLL, WM_LBUTTONDOWN, WM_LBUTTONUP,
       WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SIZE, WM_MOVE, WM_PAINT, WM_MOUSEMOVE, WM_CONTEXTMENU, WM_INITMENUPOPUP, WM_MENUSELECT, WM_EXITSIZEMOVE,
       WM_ENTERSIZEMOVE, SIZE_MAXIMIZED, SIZE_MINIMIZED, WM_KEYDOWN, WM_KEYUP, WM_CHAR, WM_MOUSEWHEEL, WM_DROPFILES, GET_WHEEL_DELTA_WPARAM,
-      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP};
+      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP, WM_DRAWITEM, WM_LBUTTONDBLCLK, WM_SETFOCUS, WM_KILLFOCUS, WM_ENABLE, WM_WINDOWPOSCHANGING, WM_NCDESTROY,
+      WM_SYSCOLORCHANGE, WM_THEMECHANGED, WM_SETTINGCHANGE, SWP_NOSIZE, DRAWITEMSTRUCT, WINDOWPOS, ODT_MENU, WM_HELP, HELPINFO, HELPINFO_WINDOW};
     use winapi::um::shellapi::{NIN_BALLOONSHOW, NIN_BALLOONHIDE, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK};
     use winapi::um::winnt::WCHAR;
     use winapi::shared::minwindef::{HIWORD, LOWORD};
 
     let callback_ptr = data as *mut *const Callback;
-    let callback: &Callback = &**callback_ptr;
+    let user_callback: &Callback = &**callback_ptr;
+    let guarded_callback = move |evt, evt_data, handle| {
+        if !events_suppressed(&handle) {
+            catch_event_panic(|| user_callback(evt, evt_data, handle));
+        }
+    };
+    let callback: &Callback = &guarded_callback;
     let base_handle = ControlHandle::Hwnd(hwnd);
 
     match msg {
@@ -634,7 +846,7 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let class_name = OsString::from_wide(&class_name_raw[..count]).into_string().unwrap_or("".to_string());
 
             match &class_name as &str {
-                "Button" => callback(button_commands(message), NO_DATA, handle),
+                "Button" => callback(button_commands(message), button_data(child_handle, message), handle),
                 "Edit" => callback(edit_commands(message), NO_DATA, handle),
                 "ComboBox" => callback(combo_commands(message), NO_DATA, handle),
                 "Static" => callback(static_commands(child_handle, message), NO_DATA, handle),
@@ -645,7 +857,17 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_CONTEXTMENU => {
             let target_handle = w as HWND;
             let handle = ControlHandle::Hwnd(target_handle);
-            callback(Event::OnContextMenu, NO_DATA, handle);
+            callback(Event::OnContextMenu, context_menu_data(target_handle, l), handle);
+        },
+        WM_HELP => {
+            let info = &*(l as *const HELPINFO);
+            if info.iContextType == HELPINFO_WINDOW as i32 {
+                let handle = ControlHandle::Hwnd(info.hItemHandle as HWND);
+                callback(Event::OnHelp, EventData::OnHelp([info.MousePos.x, info.MousePos.y]), handle);
+
+                // The default behaviour sends the message to the parent, which would raise the event again
+                return 1;
+            }
         },
         NWG_TRAY => {
             let msg = LOWORD(l as u32) as u32;
@@ -658,9 +880,10 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
                 NIN_BALLOONUSERCLICK => callback(Event::OnTrayNotificationUserClose, NO_DATA, handle),
                 WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), NO_DATA,  handle), 
                 WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), NO_DATA, handle), 
+                WM_LBUTTONDBLCLK => callback(Event::OnTrayNotificationDoubleClick, NO_DATA, handle),
                 WM_RBUTTONUP => {
                     callback(Event::OnMousePress(MousePressEvent::MousePressRightUp), NO_DATA, handle);
-                    callback(Event::OnContextMenu, NO_DATA, handle);
+                    callback(Event::OnContextMenu, cursor_data(), handle);
                 }, 
                 WM_RBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressRightDown), NO_DATA, handle),
                 WM_MOUSEMOVE => callback(Event::OnMouseMove, NO_DATA, handle),
@@ -671,13 +894,39 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             match w {
                 SIZE_MAXIMIZED => callback(Event::OnWindowMaximize, NO_DATA, base_handle),
                 SIZE_MINIMIZED => callback(Event::OnWindowMinimize, NO_DATA, base_handle),
-                _ => callback(Event::OnResize, NO_DATA, base_handle)
+                _ => {
+                    let (width, height) = crate::win32::high_dpi::physical_to_logical(LOWORD(l as u32) as i32, HIWORD(l as u32) as i32);
+                    let size = [width as u32, height as u32];
+                    let previous_size = PREVIOUS_SIZES.with(|sizes| sizes.borrow().get(&(hwnd as usize)).copied()).unwrap_or(size);
+                    callback(Event::OnResize, EventData::OnResize(ResizeData { size, previous_size }), base_handle)
+                }
             }
         },
+        WM_WINDOWPOSCHANGING => {
+            let pos = &*(l as *const WINDOWPOS);
+            if pos.flags & SWP_NOSIZE == 0 {
+                let (width, height) = super::window_helper::get_window_size(hwnd);
+                PREVIOUS_SIZES.with(|sizes| sizes.borrow_mut().insert(hwnd as usize, [width, height]));
+            }
+        },
+        WM_NCDESTROY => {
+            PREVIOUS_SIZES.with(|sizes| sizes.borrow_mut().remove(&(hwnd as usize)));
+            PENDING_SYSTEM_CHANGES.with(|changes| changes.borrow_mut().remove(&(hwnd as usize)));
+            super::window_helper::clear_shortcuts(hwnd);
+            super::window_helper::set_dialog_navigation(hwnd, true);
+        },
         WM_PAINT => {
             let data = EventData::OnPaint(PaintData { hwnd } );
             callback(Event::OnPaint, data, base_handle)
         },
+        WM_DRAWITEM => {
+            let info = &*(l as *const DRAWITEMSTRUCT);
+            if info.CtlType != ODT_MENU {
+                let data = EventData::OnDrawItem(DrawItemData { inner: info });
+                callback(Event::OnDrawItem, data, ControlHandle::Hwnd(info.hwndItem));
+                return 1;
+            }
+        },
         WM_DROPFILES => {
             let data = EventData::OnFileDrop(DropFiles { drop: w as _ });
             callback(Event::OnFileDrop, data, base_handle)
@@ -689,17 +938,36 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_CHAR => callback(Event::OnChar, EventData::OnChar(char::from_u32(w as u32).unwrap_or('?')), base_handle),
         WM_EXITSIZEMOVE => callback(Event::OnResizeEnd, NO_DATA, base_handle),
         WM_ENTERSIZEMOVE => callback(Event::OnResizeBegin, NO_DATA, base_handle),
-        WM_TIMER => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
-        WM_MOVE => callback(Event::OnMove, NO_DATA, base_handle),
+        WM_TIMER => match w == SYSTEM_CHANGES_TIMER {
+            true => dispatch_system_changes(hwnd),
+            false => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32))
+        },
+        WM_SYSCOLORCHANGE | WM_THEMECHANGED | WM_SETTINGCHANGE => queue_system_changes(hwnd, msg, l),
+        WM_SETFOCUS => callback(Event::OnFocus, NO_DATA, base_handle),
+        WM_KILLFOCUS => callback(Event::OnFocusLost, NO_DATA, base_handle),
+        WM_ENABLE => callback(Event::OnEnabledChanged, EventData::OnEnabledChanged(w != 0), base_handle),
+        WM_MOVE => {
+            // Same as GET_X_LPARAM / GET_Y_LPARAM. The coordinates can be negative on multi monitor setups
+            let x = LOWORD(l as u32) as i16 as i32;
+            let y = HIWORD(l as u32) as i16 as i32;
+            let (x, y) = crate::win32::high_dpi::physical_to_logical(x, y);
+            callback(Event::OnMove, EventData::OnMove([x, y]), base_handle)
+        },
         WM_HSCROLL => callback(Event::OnHorizontalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
         WM_VSCROLL => callback(Event::OnVerticalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
         WM_MOUSEMOVE => callback(Event::OnMouseMove, NO_DATA, base_handle), 
-        WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), NO_DATA,  base_handle), 
-        WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), NO_DATA, base_handle), 
-        WM_RBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressRightUp), NO_DATA, base_handle), 
-        WM_RBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressRightDown), NO_DATA, base_handle),
+        WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), mouse_data(w),  base_handle), 
+        WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), mouse_data(w), base_handle), 
+        WM_RBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressRightUp), mouse_data(w), base_handle), 
+        WM_RBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressRightDown), mouse_data(w), base_handle),
         NOTICE_MESSAGE => callback(Event::OnNotice, NO_DATA, ControlHandle::Notice(hwnd, w as u32)),
         NWG_INIT => callback(Event::OnInit, NO_DATA, base_handle),
+        NWG_VALIDATION_FAILED => callback(Event::OnValidationFailed, NO_DATA, base_handle),
+        NWG_SHORTCUT => callback(Event::OnShortcut, EventData::OnShortcut(w as u32), base_handle),
+        NWG_ITEM_MOVED => callback(Event::OnListBoxItemMoved, EventData::OnListBoxItemMoved { from: w, to: l as usize }, base_handle),
+        NWG_SUBMIT => callback(Event::OnComboBoxSubmit, EventData::OnComboBoxSubmit(super::window_helper::get_window_text(hwnd)), base_handle),
+        NWG_DEBOUNCED => callback(Event::OnTextInputDebounced, NO_DATA, base_handle),
+        NWG_SYSTEM_CHANGED => callback(Event::OnSystemColorsChanged, EventData::OnSystemColorsChanged(SystemChanges::from_bits_truncate(w as u32)), base_handle),
         WM_CLOSE => {
             let mut should_exit = true;
             let data = EventData::OnWindowClose(WindowCloseData { data: &mut should_exit as *mut bool });
@@ -723,7 +991,7 @@ unsafe extern "system" fn process_raw_events(hwnd: HWND, msg: UINT, w: WPARAM, l
     let callback_wrapper_ptr = data as *mut *mut RawCallback;
     let callback: Box<RawCallback> = Box::from_raw(*callback_wrapper_ptr);
 
-    let result = callback(hwnd, msg, w, l);
+    let result = catch_event_panic(|| callback(hwnd, msg, w, l)).flatten();
     Box::into_raw(callback);
 
     match result {
@@ -732,6 +1000,29 @@ unsafe extern "system" fn process_raw_events(hwnd: HWND, msg: UINT, w: WPARAM, l
     }
 }
 
+/// Return the check state of toggle buttons for `OnButtonClick`
+fn button_data(handle: HWND, m: u16) -> EventData {
+    use winapi::um::winuser::{BN_CLICKED, BM_GETCHECK, BST_CHECKED, BST_INDETERMINATE, BS_TYPEMASK, BS_CHECKBOX, BS_AUTOCHECKBOX,
+        BS_3STATE, BS_AUTO3STATE, BS_RADIOBUTTON, BS_AUTORADIOBUTTON};
+    use crate::CheckBoxState;
+
+    if m != BN_CLICKED {
+        return NO_DATA;
+    }
+
+    match super::window_helper::get_style(handle) & BS_TYPEMASK {
+        BS_CHECKBOX | BS_AUTOCHECKBOX | BS_3STATE | BS_AUTO3STATE | BS_RADIOBUTTON | BS_AUTORADIOBUTTON => {
+            let state = match super::window_helper::send_message(handle, BM_GETCHECK, 0, 0) as usize {
+                BST_CHECKED => CheckBoxState::Checked,
+                BST_INDETERMINATE => CheckBoxState::Indeterminate,
+                _ => CheckBoxState::Unchecked
+            };
+            EventData::OnButtonClick(state)
+        },
+        _ => NO_DATA
+    }
+}
+
 fn button_commands(m: u16) -> Event {
     use winapi::um::winuser::{BN_CLICKED, BN_DBLCLK};
     match m {
@@ -750,12 +1041,57 @@ fn edit_commands(m: u16) -> Event {
     }
 }
 
+/// Event data of the mouse button messages. `w` holds the `MK_*` flags.
+fn mouse_data(w: WPARAM) -> EventData {
+    use crate::events::MouseModifiers;
+    EventData::OnMousePress(MouseModifiers::from_bits_truncate(w as u32))
+}
+
+/// Event data of `WM_CONTEXTMENU`. `l` holds the screen position of the mouse, or -1 if the menu was requested with the keyboard.
+unsafe fn context_menu_data(hwnd: HWND, l: LPARAM) -> EventData {
+    use winapi::um::winuser::GetWindowRect;
+    use winapi::shared::windef::RECT;
+
+    // Same as GET_X_LPARAM / GET_Y_LPARAM. The coordinates can be negative on multi monitor setups
+    let x = (l & 0xFFFF) as i16 as i32;
+    let y = ((l >> 16) & 0xFFFF) as i16 as i32;
+
+    if x == -1 && y == -1 {
+        let mut r: RECT = mem::zeroed();
+        GetWindowRect(hwnd, &mut r);
+        EventData::OnContextMenu([(r.left + r.right) / 2, (r.top + r.bottom) / 2])
+    } else {
+        EventData::OnContextMenu([x, y])
+    }
+}
+
+/// Event data of the context menu of the system tray. The position is the cursor position.
+unsafe fn cursor_data() -> EventData {
+    use winapi::um::winuser::GetCursorPos;
+    use winapi::shared::windef::POINT;
+
+    let mut p = POINT { x: 0, y: 0 };
+    GetCursorPos(&mut p);
+    EventData::OnContextMenu([p.x, p.y])
+}
+
 fn combo_commands(m: u16) -> Event {
-    use winapi::um::winuser::{CBN_CLOSEUP, CBN_DROPDOWN, CBN_SELCHANGE};
+    use winapi::um::winuser::{CBN_CLOSEUP, CBN_DROPDOWN, CBN_SELCHANGE, CBN_EDITCHANGE};
     match m {
         CBN_CLOSEUP => Event::OnComboBoxClosed,
         CBN_DROPDOWN => Event::OnComboBoxDropdown,
         CBN_SELCHANGE => Event::OnComboxBoxSelection,
+        CBN_EDITCHANGE => Event::OnComboBoxTextChanged,
+        _ => Event::Unknown
+    }
+}
+
+#[cfg(feature = "rich-textbox")]
+fn rich_edit_commands(m: u32) -> Event {
+    use super::richedit::EN_SELCHANGE;
+
+    match m {
+        EN_SELCHANGE => Event::OnRichTextBoxSelectionChanged,
         _ => Event::Unknown
     }
 }
@@ -947,6 +1283,47 @@ unsafe fn listbox_commands(m: u16) -> Event {
     }
 }
 
+/// Save the kind of system change notified by `msg` and (re)start the timer that will dispatch `OnSystemColorsChanged`.
+/// The system sends many of those messages in a row when the theme changes, so they are merged in a single event.
+unsafe fn queue_system_changes(hwnd: HWND, msg: UINT, l: LPARAM) {
+    use winapi::um::winuser::{SetTimer, WM_SYSCOLORCHANGE, WM_THEMECHANGED, WS_CHILD};
+    use crate::events::SystemChanges;
+
+    // `WM_THEMECHANGED` is also sent to the children. Only top level windows receive the event.
+    if super::window_helper::get_style(hwnd) & WS_CHILD == WS_CHILD {
+        return;
+    }
+
+    let change = match msg {
+        WM_SYSCOLORCHANGE => SystemChanges::COLORS,
+        WM_THEMECHANGED => SystemChanges::THEME,
+        _ => match l != 0 && super::base_helper::from_wide_ptr(l as *mut u16, None) == "ImmersiveColorSet" {
+            true => SystemChanges::COLOR_MODE,
+            false => SystemChanges::SETTINGS,
+        }
+    };
+
+    PENDING_SYSTEM_CHANGES.with(|changes| {
+        let mut changes = changes.borrow_mut();
+        *changes.entry(hwnd as usize).or_insert(0) |= change.bits();
+    });
+
+    SetTimer(hwnd, SYSTEM_CHANGES_TIMER, SYSTEM_CHANGES_DELAY, None);
+}
+
+/// Dispatch the system changes merged by `queue_system_changes` to every event handler bound to the window
+unsafe fn dispatch_system_changes(hwnd: HWND) {
+    use winapi::um::winuser::KillTimer;
+
+    KillTimer(hwnd, SYSTEM_CHANGES_TIMER);
+
+    // Sent so that every handler in the subclass chain receives the event, not only the one that got the timer message
+    let changes = PENDING_SYSTEM_CHANGES.with(|changes| changes.borrow_mut().remove(&(hwnd as usize)));
+    if let Some(changes) = changes {
+        super::window_helper::send_message(hwnd, NWG_SYSTEM_CHANGED, changes as WPARAM, 0);
+    }
+}
+
 unsafe fn handle_tooltip_callback<'a>(notif: *mut NMTTDISPINFOW, callback: &Callback) {
     use crate::events::ToolTipTextData;
 
@@ -975,6 +1352,8 @@ unsafe fn handle_default_notify_callback<'a>(notif_raw: *const NMHDR, callback:
         "SysDateTimePick32" => callback(datetimepick_commands(code), NO_DATA, handle),
         "SysTabControl32" => callback(tabs_commands(code), NO_DATA, handle),
         "msctls_trackbar32" => callback(track_commands(code), NO_DATA, handle),
+        #[cfg(feature = "rich-textbox")]
+        "RICHEDIT50W" => callback(rich_edit_commands(code), NO_DATA, handle),
         winapi::um::commctrl::WC_TREEVIEW => callback(tree_commands(code), tree_data(code, notif_raw), handle),
         winapi::um::commctrl::WC_LISTVIEW => callback(list_view_commands(code), list_view_data(code, notif_raw), handle),
         _ => {}
@@ -1,7 +1,7 @@
 use super::base_helper::{to_utf16, from_utf16};
 use super::high_dpi;
 use winapi::shared::windef::{HFONT, HWND};
-use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, LRESULT};
+use winapi::shared::minwindef::{BOOL, UINT, WPARAM, LPARAM, LRESULT};
 use winapi::um::winuser::{WM_USER, WNDCLASSEXW};
 use winapi::ctypes::c_int;
 use std::{ptr, mem};
@@ -10,6 +10,17 @@ use std::{ptr, mem};
 pub const NOTICE_MESSAGE: UINT = WM_USER+100;
 pub const NWG_INIT: UINT = WM_USER + 101;
 pub const NWG_TRAY: UINT = WM_USER + 102;
+pub const NWG_VALIDATION_FAILED: UINT = WM_USER + 103;
+pub const NWG_SHORTCUT: UINT = WM_USER + 104;
+pub const NWG_ITEM_MOVED: UINT = WM_USER + 105;
+pub const NWG_SUBMIT: UINT = WM_USER + 106;
+pub const NWG_DEBOUNCED: UINT = WM_USER + 107;
+pub const NWG_SYSTEM_CHANGED: UINT = WM_USER + 108;
+
+lazy_static! {
+    /// Name of the window property set on top level windows that opted out of dialog navigation
+    static ref NO_DIALOG_NAVIGATION: Vec<u16> = to_utf16("NwgNoDialogNavigation");
+}
 
 
 /// Returns the class info of a hwnd handle
@@ -177,12 +188,242 @@ pub fn get_style(handle: HWND) -> UINT {
     get_window_long(handle, GWL_STYLE) as UINT
 }
 
-#[cfg(any(feature = "list-view", feature = "progress-bar"))]
 pub fn set_style(handle: HWND, style: u32) {
     use ::winapi::um::winuser::GWL_STYLE;
     set_window_long(handle, GWL_STYLE, style as usize);
 }
 
+pub fn get_ex_style(handle: HWND) -> UINT {
+    use ::winapi::um::winuser::GWL_EXSTYLE;
+    get_window_long(handle, GWL_EXSTYLE) as UINT
+}
+
+pub fn set_ex_style(handle: HWND, style: u32) {
+    use ::winapi::um::winuser::GWL_EXSTYLE;
+    set_window_long(handle, GWL_EXSTYLE, style as usize);
+}
+
+/// Returns `false` if the keyboard navigation (tab, arrows) was disabled for the top level window
+pub fn get_dialog_navigation(handle: HWND) -> bool {
+    use winapi::um::winuser::GetPropW;
+    unsafe { GetPropW(handle, NO_DIALOG_NAVIGATION.as_ptr()).is_null() }
+}
+
+/// Disable or enable the keyboard navigation of a top level window. The window property is removed on `WM_NCDESTROY`.
+pub fn set_dialog_navigation(handle: HWND, enabled: bool) {
+    use winapi::um::winuser::{SetPropW, RemovePropW};
+
+    unsafe {
+        match enabled {
+            true => { RemovePropW(handle, NO_DIALOG_NAVIGATION.as_ptr()); },
+            false => { SetPropW(handle, NO_DIALOG_NAVIGATION.as_ptr(), 1 as _); }
+        }
+    }
+}
+
+/// Render a window and its children in a 32 bits BGRA buffer. Returns (width, height, pixels)
+pub unsafe fn capture_window(handle: HWND) -> Option<(u32, u32, Vec<u8>)> {
+    use winapi::um::winuser::{GetWindowRect, GetDC, ReleaseDC, PrintWindow};
+    use winapi::um::wingdi::{CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, DeleteObject, DeleteDC, GetDIBits,
+        BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS};
+    use winapi::shared::windef::RECT;
+
+    // Also renders the content of windows using DirectComposition. Not defined in winapi.
+    const PW_RENDERFULLCONTENT: u32 = 2;
+
+    let mut r: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut r);
+    let (width, height) = (r.right - r.left, r.bottom - r.top);
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let screen_dc = GetDC(ptr::null_mut());
+    let dc = CreateCompatibleDC(screen_dc);
+    let bitmap = CreateCompatibleBitmap(screen_dc, width, height);
+    let old_bitmap = SelectObject(dc, bitmap as _);
+
+    let printed = PrintWindow(handle, dc, PW_RENDERFULLCONTENT) != 0;
+    SelectObject(dc, old_bitmap);
+
+    let mut pixels: Vec<u8> = vec![0; (width * height * 4) as usize];
+    let mut info: BITMAPINFO = mem::zeroed();
+    info.bmiHeader = BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+        biWidth: width,
+        biHeight: -height, // Negative height means the rows go from top to bottom
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB,
+        ..mem::zeroed()
+    };
+
+    let copied = printed && GetDIBits(dc, bitmap, 0, height as u32, pixels.as_mut_ptr() as _, &mut info, DIB_RGB_COLORS) != 0;
+
+    DeleteObject(bitmap as _);
+    DeleteDC(dc);
+    ReleaseDC(ptr::null_mut(), screen_dc);
+
+    if !copied {
+        return None;
+    }
+
+    // GDI does not write the alpha channel
+    for pixel in pixels.chunks_mut(4) {
+        pixel[3] = 255;
+    }
+
+    Some((width as u32, height as u32, pixels))
+}
+
+/// Force the window to recompute its non client area (borders, caption, scrollbars) after a style change
+pub unsafe fn refresh_frame(handle: HWND) {
+    use winapi::um::winuser::SetWindowPos;
+    use winapi::um::winuser::{SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+
+    SetWindowPos(handle, ptr::null_mut(), 0, 0, 0, 0, SWP_FRAMECHANGED|SWP_NOMOVE|SWP_NOSIZE|SWP_NOZORDER|SWP_NOACTIVATE|SWP_NOOWNERZORDER);
+}
+
+/// Enables the redraw of a window and repaints it when dropped, even if the closure of `without_redraw` panics.
+struct RedrawGuard(HWND);
+
+impl Drop for RedrawGuard {
+    fn drop(&mut self) {
+        use winapi::um::winuser::{WM_SETREDRAW, InvalidateRect};
+
+        send_message(self.0, WM_SETREDRAW, 1, 0);
+        unsafe { InvalidateRect(self.0, ptr::null(), 1); }
+    }
+}
+
+/// Execute `cb` with the redraw of the window disabled, then repaint the window once.
+/// If the window is already hidden or has its redraw disabled, `cb` is executed as is.
+pub fn without_redraw<F, R>(handle: HWND, cb: F) -> R
+    where F: FnOnce() -> R
+{
+    use winapi::um::winuser::{WM_SETREDRAW, WS_VISIBLE};
+
+    if get_style(handle) & WS_VISIBLE == 0 {
+        return cb();
+    }
+
+    send_message(handle, WM_SETREDRAW, 0, 0);
+    let _guard = RedrawGuard(handle);
+    cb()
+}
+
+thread_local! {
+    /// Keyboard shortcuts registered with `Window::add_shortcut`: (top level window, modifiers, virtual key, id)
+    static SHORTCUTS: std::cell::RefCell<Vec<(usize, u32, u32, u32)>> = std::cell::RefCell::new(Vec::new());
+}
+
+thread_local! {
+    /// Custom frames of the borderless windows: (top level window, caption area, resize border). Sizes are in logical pixels.
+    static CUSTOM_FRAMES: std::cell::RefCell<Vec<(usize, Option<[i32; 4]>, i32)>> = std::cell::RefCell::new(Vec::new());
+}
+
+/// Return the caption area and the resize border of a top level window
+pub fn get_custom_frame(handle: HWND) -> (Option<[i32; 4]>, i32) {
+    CUSTOM_FRAMES.with(|f| {
+        f.borrow().iter()
+            .find(|&&(h, _, _)| h == handle as usize)
+            .map(|&(_, caption, border)| (caption, border))
+            .unwrap_or((None, 0))
+    })
+}
+
+/// Set the caption area and the resize border of a top level window. The frame is removed if both are empty.
+pub fn set_custom_frame(handle: HWND, caption: Option<[i32; 4]>, border: i32) {
+    CUSTOM_FRAMES.with(|f| {
+        let mut frames = f.borrow_mut();
+        frames.retain(|&(h, _, _)| h != handle as usize);
+        if caption.is_some() || border > 0 {
+            frames.push((handle as usize, caption, border));
+        }
+    });
+}
+
+/// Answer `WM_NCHITTEST` for the client area of a window with a custom frame. Return `None` to use the default hit test.
+pub unsafe fn custom_frame_hit_test(handle: HWND, l: LPARAM) -> Option<LRESULT> {
+    use winapi::um::winuser::{ScreenToClient, GetClientRect, IsZoomed, HTCAPTION, HTLEFT, HTRIGHT, HTTOP, HTBOTTOM,
+        HTTOPLEFT, HTTOPRIGHT, HTBOTTOMLEFT, HTBOTTOMRIGHT};
+    use winapi::shared::windef::{POINT, RECT};
+
+    let (caption, border) = get_custom_frame(handle);
+    if caption.is_none() && border == 0 {
+        return None;
+    }
+
+    let mut pt = POINT { x: (l & 0xFFFF) as i16 as i32, y: ((l >> 16) & 0xFFFF) as i16 as i32 };
+    let mut client: RECT = mem::zeroed();
+    ScreenToClient(handle, &mut pt);
+    GetClientRect(handle, &mut client);
+
+    if pt.x < 0 || pt.y < 0 || pt.x >= client.right || pt.y >= client.bottom {
+        return None;
+    }
+
+    // A maximized window cannot be resized
+    let (border, _) = high_dpi::logical_to_physical(border, 0);
+    if border > 0 && IsZoomed(handle) == 0 {
+        let left = pt.x < border;
+        let right = pt.x >= client.right - border;
+        let top = pt.y < border;
+        let bottom = pt.y >= client.bottom - border;
+
+        let hit = match (left, right, top, bottom) {
+            (true, _, true, _) => Some(HTTOPLEFT),
+            (_, true, true, _) => Some(HTTOPRIGHT),
+            (true, _, _, true) => Some(HTBOTTOMLEFT),
+            (_, true, _, true) => Some(HTBOTTOMRIGHT),
+            (true, _, _, _) => Some(HTLEFT),
+            (_, true, _, _) => Some(HTRIGHT),
+            (_, _, true, _) => Some(HTTOP),
+            (_, _, _, true) => Some(HTBOTTOM),
+            _ => None
+        };
+
+        if let Some(hit) = hit {
+            return Some(hit as LRESULT);
+        }
+    }
+
+    if let Some([x, y, w, h]) = caption {
+        let (x, y) = high_dpi::logical_to_physical(x, y);
+        let (w, h) = high_dpi::logical_to_physical(w, h);
+        if pt.x >= x && pt.x < x + w && pt.y >= y && pt.y < y + h {
+            return Some(HTCAPTION as LRESULT);
+        }
+    }
+
+    None
+}
+
+/// Register a keyboard shortcut on a top level window. Replace the id if the shortcut was already registered.
+pub fn add_shortcut(handle: HWND, modifiers: u32, key: u32, id: u32) {
+    remove_shortcut(handle, modifiers, key);
+    SHORTCUTS.with(|s| s.borrow_mut().push((handle as usize, modifiers, key, id)));
+}
+
+/// Unregister a keyboard shortcut of a top level window
+pub fn remove_shortcut(handle: HWND, modifiers: u32, key: u32) {
+    SHORTCUTS.with(|s| s.borrow_mut().retain(|&(h, m, k, _)| !(h == handle as usize && m == modifiers && k == key)));
+}
+
+/// Unregister all the keyboard shortcuts of a top level window
+pub fn clear_shortcuts(handle: HWND) {
+    SHORTCUTS.with(|s| s.borrow_mut().retain(|&(h, _, _, _)| h != handle as usize));
+}
+
+/// Return the id of the keyboard shortcut registered on a top level window
+pub fn find_shortcut(handle: HWND, modifiers: u32, key: u32) -> Option<u32> {
+    SHORTCUTS.with(|s| {
+        s.borrow().iter()
+            .find(|&&(h, m, k, _)| h == handle as usize && m == modifiers && k == key)
+            .map(|&(_, _, _, id)| id)
+    })
+}
+
 pub fn send_message(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
     unsafe { ::winapi::um::winuser::SendMessageW(hwnd, msg, w, l) }
 }
@@ -207,7 +448,8 @@ pub unsafe fn get_window_text(handle: HWND) -> String {
 
     let mut buffer: Vec<u16> = vec![0; buffer_size + 1];
 
-    if GetWindowTextW(handle, buffer.as_mut_ptr(), buffer_size as c_int) == 0 {
+    // The max count includes the null character. Otherwise the last UTF-16 unit (ex: half of an emoji) is cut.
+    if GetWindowTextW(handle, buffer.as_mut_ptr(), buffer.len() as c_int) == 0 {
         String::new()
     } else {
         from_utf16(&buffer[..])
@@ -230,6 +472,35 @@ pub unsafe fn set_window_position(handle: HWND, x: i32, y: i32) {
 }
 
 
+/// Move and resize many windows at once using logical coordinates. The windows are repainted once, after they were all moved.
+/// All the windows must have the same parent.
+pub unsafe fn set_windows_bounds(bounds: &[(HWND, i32, i32, u32, u32)]) {
+    use winapi::um::winuser::{BeginDeferWindowPos, DeferWindowPos, EndDeferWindowPos, SetWindowPos};
+    use winapi::um::winuser::{SWP_NOZORDER, SWP_NOACTIVATE, SWP_NOCOPYBITS, SWP_NOOWNERZORDER};
+
+    // Like `set_window_size`, the old content is not copied because the resized controls repaint themselves
+    let flags = SWP_NOZORDER|SWP_NOACTIVATE|SWP_NOCOPYBITS|SWP_NOOWNERZORDER;
+    let mut defer = BeginDeferWindowPos(bounds.len() as c_int);
+
+    for &(handle, x, y, w, h) in bounds {
+        let (x, y) = high_dpi::logical_to_physical(x, y);
+        let (w, h) = high_dpi::logical_to_physical(w as i32, h as i32);
+
+        // If the system cannot defer the position anymore, the remaining windows are moved one at a time
+        if !defer.is_null() {
+            defer = DeferWindowPos(defer, handle, ptr::null_mut(), x, y, w, h, flags);
+        }
+
+        if defer.is_null() {
+            SetWindowPos(handle, ptr::null_mut(), x, y, w, h, flags);
+        }
+    }
+
+    if !defer.is_null() {
+        EndDeferWindowPos(defer);
+    }
+}
+
 pub unsafe fn set_window_after(handle: HWND, after: Option<HWND>) {
     use winapi::um::winuser::SetWindowPos;
     use winapi::um::winuser::{HWND_TOP, SWP_NOSIZE, SWP_NOMOVE, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
@@ -261,6 +532,290 @@ pub unsafe fn get_window_position(handle: HWND) -> (i32, i32) {
     high_dpi::physical_to_logical(x, y)
 }
 
+/// Return the position of a window in logical coordinates. If `screen` is false, a child window position is
+/// relative to the client area of its parent. Top level windows always use screen coordinates.
+pub unsafe fn get_window_position_in(handle: HWND, screen: bool) -> (i32, i32) {
+    use winapi::um::winuser::{GetWindowRect, ScreenToClient, GetParent, WS_CHILD};
+    use winapi::shared::windef::{RECT, POINT};
+
+    let mut r: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut r);
+
+    let mut pt = POINT { x: r.left, y: r.top };
+    if !screen && get_style(handle) & WS_CHILD == WS_CHILD {
+        ScreenToClient(GetParent(handle), &mut pt);
+    }
+
+    high_dpi::physical_to_logical(pt.x, pt.y)
+}
+
+/// Move a window using logical coordinates. If `screen` is false, a child window position is
+/// relative to the client area of its parent. Top level windows always use screen coordinates.
+/// Returns the value returned by `SetWindowPos`.
+pub unsafe fn set_window_position_in(handle: HWND, x: i32, y: i32, screen: bool) -> BOOL {
+    use winapi::um::winuser::{SetWindowPos, ScreenToClient, GetParent, WS_CHILD};
+    use winapi::um::winuser::{SWP_NOZORDER, SWP_NOSIZE, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+    use winapi::shared::windef::POINT;
+
+    let (x, y) = high_dpi::logical_to_physical(x, y);
+    let mut pt = POINT { x, y };
+    if screen && get_style(handle) & WS_CHILD == WS_CHILD {
+        ScreenToClient(GetParent(handle), &mut pt);
+    }
+
+    SetWindowPos(handle, ptr::null_mut(), pt.x as c_int, pt.y as c_int, 0, 0, SWP_NOZORDER|SWP_NOSIZE|SWP_NOACTIVATE|SWP_NOOWNERZORDER)
+}
+
+/// Return the position and the size of the window rectangle (borders included) using logical coordinates. If `screen` is false,
+/// a child window position is relative to the client area of its parent. Top level windows always use screen coordinates.
+pub unsafe fn get_window_bounds_in(handle: HWND, screen: bool) -> (i32, i32, u32, u32) {
+    use winapi::um::winuser::{GetWindowRect, ScreenToClient, GetParent, WS_CHILD};
+    use winapi::shared::windef::{RECT, POINT};
+
+    let mut r: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut r);
+
+    let mut pt = POINT { x: r.left, y: r.top };
+    if !screen && get_style(handle) & WS_CHILD == WS_CHILD {
+        ScreenToClient(GetParent(handle), &mut pt);
+    }
+
+    let (x, y) = high_dpi::physical_to_logical(pt.x, pt.y);
+    let (w, h) = high_dpi::physical_to_logical(r.right - r.left, r.bottom - r.top);
+
+    (x, y, w as u32, h as u32)
+}
+
+/// Move and resize a window with a single `SetWindowPos` using logical coordinates. The size is the size of the window rectangle (borders included).
+/// If `screen` is false, a child window position is relative to the client area of its parent. Top level windows always use screen coordinates.
+/// Returns the value returned by `SetWindowPos`.
+pub unsafe fn set_window_bounds_in(handle: HWND, x: i32, y: i32, w: u32, h: u32, screen: bool) -> BOOL {
+    use winapi::um::winuser::{SetWindowPos, ScreenToClient, GetParent, WS_CHILD};
+    use winapi::um::winuser::{SWP_NOZORDER, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+    use winapi::shared::windef::POINT;
+
+    let (x, y) = high_dpi::logical_to_physical(x, y);
+    let (w, h) = high_dpi::logical_to_physical(w as i32, h as i32);
+    let mut pt = POINT { x, y };
+    if screen && get_style(handle) & WS_CHILD == WS_CHILD {
+        ScreenToClient(GetParent(handle), &mut pt);
+    }
+
+    SetWindowPos(handle, ptr::null_mut(), pt.x as c_int, pt.y as c_int, w as c_int, h as c_int, SWP_NOZORDER|SWP_NOACTIVATE|SWP_NOOWNERZORDER)
+}
+
+/// Scroll the parent of a window so that the window is fully visible. The parent scroll position is expected to be in pixels
+/// and its content is moved with `ScrollWindowEx`. Return `false` if the parent was not scrolled.
+pub unsafe fn scroll_into_view(handle: HWND) -> bool {
+    use winapi::um::winuser::{GetWindowRect, GetClientRect, MapWindowPoints, GetScrollInfo, SetScrollInfo, ScrollWindowEx, SCROLLINFO,
+        SIF_RANGE, SIF_PAGE, SIF_POS, SB_HORZ, SB_VERT, WS_HSCROLL, WS_VSCROLL, SW_SCROLLCHILDREN, SW_INVALIDATE, SW_ERASE};
+    use winapi::shared::windef::{RECT, POINT};
+
+    let parent = get_window_parent(handle);
+    if parent.is_null() {
+        return false;
+    }
+
+    let mut rect: RECT = mem::zeroed();
+    let mut client: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut rect);
+    GetClientRect(parent, &mut client);
+    MapWindowPoints(ptr::null_mut(), parent, &mut rect as *mut RECT as *mut POINT, 2);
+
+    // Minimal move to show the window. If the window is larger than the parent, its top left corner is shown.
+    fn delta(start: i32, end: i32, view: i32) -> i32 {
+        if start < 0 { start }
+        else if end > view { (end - view).min(start) }
+        else { 0 }
+    }
+
+    let style = get_style(parent);
+    let scroll = |bar: i32, flag: u32, d: i32| -> i32 {
+        if d == 0 || style & flag != flag {
+            return 0;
+        }
+
+        let mut info: SCROLLINFO = mem::zeroed();
+        info.cbSize = mem::size_of::<SCROLLINFO>() as u32;
+        info.fMask = SIF_RANGE | SIF_PAGE | SIF_POS;
+        if GetScrollInfo(parent, bar, &mut info) == 0 {
+            return 0;
+        }
+
+        let old = info.nPos;
+        let max = (info.nMax - (info.nPage as i32).max(1) + 1).max(info.nMin);
+        let pos = (old + d).min(max).max(info.nMin);
+
+        info.fMask = SIF_POS;
+        info.nPos = pos;
+        SetScrollInfo(parent, bar, &info, 1);
+
+        pos - old
+    };
+
+    let dx = scroll(SB_HORZ as i32, WS_HSCROLL, delta(rect.left, rect.right, client.right));
+    let dy = scroll(SB_VERT as i32, WS_VSCROLL, delta(rect.top, rect.bottom, client.bottom));
+    if dx == 0 && dy == 0 {
+        return false;
+    }
+
+    ScrollWindowEx(parent, -dx, -dy, ptr::null(), ptr::null(), ptr::null_mut(), ptr::null_mut(), SW_SCROLLCHILDREN | SW_INVALIDATE | SW_ERASE);
+
+    true
+}
+
+/// Return the height (in logical pixels) the window needs to display its text word wrapped in `width` logical pixels.
+/// The window font, the non client area (borders, scrollbars) and the edit control margins are included.
+/// A `&` is measured as a mnemonic prefix, like the control draws it, unless the control is an edit or a static with `SS_NOPREFIX`.
+pub unsafe fn get_preferred_height(handle: HWND, width: i32) -> i32 {
+    use winapi::um::winuser::{GetDC, ReleaseDC, DrawTextW, GetWindowRect, GetClientRect, DT_CALCRECT, DT_WORDBREAK, DT_EXPANDTABS, DT_NOPREFIX, EM_GETMARGINS, SS_NOPREFIX};
+    use winapi::um::wingdi::SelectObject;
+    use winapi::shared::windef::{RECT, HGDIOBJ};
+    use winapi::shared::minwindef::{LOWORD, HIWORD};
+
+    let (width, _) = high_dpi::logical_to_physical(width, 0);
+
+    // Borders and scrollbars
+    let mut window: RECT = mem::zeroed();
+    let mut client: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut window);
+    GetClientRect(handle, &mut client);
+    let non_client_width = (window.right - window.left) - (client.right - client.left);
+    let non_client_height = (window.bottom - window.top) - (client.bottom - client.top);
+
+    // Edit controls have a margin on each side of the text and never process the `&` prefix
+    let (margins, prefix) = match get_window_class_name(handle).to_uppercase().as_str() {
+        "EDIT" => {
+            let m = send_message(handle, EM_GETMARGINS as u32, 0, 0) as u32;
+            ((LOWORD(m) + HIWORD(m)) as i32, DT_NOPREFIX)
+        },
+        "STATIC" if get_style(handle) & SS_NOPREFIX == SS_NOPREFIX => (0, DT_NOPREFIX),
+        _ => (0, 0)
+    };
+
+    let text = to_utf16(&get_window_text(handle));
+    let mut rect = RECT { left: 0, top: 0, right: (width - non_client_width - margins).max(1), bottom: 0 };
+
+    let dc = GetDC(handle);
+    let old = SelectObject(dc, get_window_font(handle) as HGDIOBJ);
+    DrawTextW(dc, text.as_ptr(), -1, &mut rect, DT_CALCRECT | DT_WORDBREAK | DT_EXPANDTABS | prefix);
+    SelectObject(dc, old);
+    ReleaseDC(handle, dc);
+
+    let (_, height) = high_dpi::physical_to_logical(0, rect.bottom + non_client_height);
+    height
+}
+
+/// Return the size (in logical pixels) a button needs to display its text and its image with the current font.
+/// Uses `BCM_GETIDEALSIZE` and falls back to measuring the text if the message is not supported (comctl32 < 6).
+pub unsafe fn get_ideal_button_size(handle: HWND) -> (u32, u32) {
+    use winapi::um::winuser::{GetDC, ReleaseDC, DrawTextW, GetIconInfo, GetSystemMetrics, ICONINFO, BM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON,
+      DT_CALCRECT, DT_SINGLELINE, SM_CXEDGE, SM_CYEDGE};
+    use winapi::um::commctrl::BCM_GETIDEALSIZE;
+    use winapi::um::wingdi::{SelectObject, GetObjectW, DeleteObject, BITMAP};
+    use winapi::shared::windef::{RECT, SIZE, HGDIOBJ, HBITMAP, HICON};
+
+    // Text padding used when the size must be computed manually
+    const PADDING_X: i32 = 16;
+    const PADDING_Y: i32 = 10;
+
+    let mut size = SIZE { cx: 0, cy: 0 };
+    let ok = send_message(handle, BCM_GETIDEALSIZE, 0, &mut size as *mut SIZE as LPARAM);
+
+    if ok == 0 || size.cx == 0 || size.cy == 0 {
+        let text = to_utf16(&get_window_text(handle));
+        let mut rect: RECT = mem::zeroed();
+
+        let dc = GetDC(handle);
+        let old = SelectObject(dc, get_window_font(handle) as HGDIOBJ);
+        DrawTextW(dc, text.as_ptr(), -1, &mut rect, DT_CALCRECT | DT_SINGLELINE);
+        SelectObject(dc, old);
+        ReleaseDC(handle, dc);
+
+        // Image size
+        let mut image = (0, 0);
+        let mut bitmap: BITMAP = mem::zeroed();
+        let bitmap_size = mem::size_of::<BITMAP>() as i32;
+        let bitmap_handle = send_message(handle, BM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0) as HBITMAP;
+        let icon_handle = send_message(handle, BM_GETIMAGE, IMAGE_ICON as WPARAM, 0) as HICON;
+        if !bitmap_handle.is_null() && GetObjectW(bitmap_handle as HGDIOBJ, bitmap_size, &mut bitmap as *mut BITMAP as _) != 0 {
+            image = (bitmap.bmWidth, bitmap.bmHeight);
+        } else if !icon_handle.is_null() {
+            let mut info: ICONINFO = mem::zeroed();
+            if GetIconInfo(icon_handle, &mut info) != 0 {
+                if GetObjectW(info.hbmMask as HGDIOBJ, bitmap_size, &mut bitmap as *mut BITMAP as _) != 0 {
+                    // Monochrome icons store the mask and the image in the same bitmap
+                    let height = match info.hbmColor.is_null() { true => bitmap.bmHeight / 2, false => bitmap.bmHeight };
+                    image = (bitmap.bmWidth, height);
+                }
+
+                DeleteObject(info.hbmMask as HGDIOBJ);
+                if !info.hbmColor.is_null() { DeleteObject(info.hbmColor as HGDIOBJ); }
+            }
+        }
+
+        let (edge_x, edge_y) = (GetSystemMetrics(SM_CXEDGE), GetSystemMetrics(SM_CYEDGE));
+        size.cx = (rect.right - rect.left) + image.0 + PADDING_X + (edge_x * 2);
+        size.cy = (rect.bottom - rect.top).max(image.1) + PADDING_Y + (edge_y * 2);
+    }
+
+    let (width, height) = high_dpi::physical_to_logical(size.cx, size.cy);
+    (width as u32, height as u32)
+}
+
+/// Center a window on its parent (or owner) if `on_parent` is true and the window has one. Otherwise, center the window
+/// in the work area of the monitor under the mouse cursor. The window is kept inside the work area of its monitor.
+pub unsafe fn center_window(handle: HWND, on_parent: bool) {
+    use winapi::um::winuser::{GetWindowRect, GetParent, GetCursorPos, MonitorFromPoint, MonitorFromWindow, GetMonitorInfoW, ScreenToClient,
+      SetWindowPos, MONITORINFO, MONITOR_DEFAULTTONEAREST, WS_CHILD, SWP_NOZORDER, SWP_NOSIZE, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+    use winapi::shared::windef::{RECT, POINT};
+
+    let mut window: RECT = mem::zeroed();
+    GetWindowRect(handle, &mut window);
+    let (width, height) = (window.right - window.left, window.bottom - window.top);
+
+    let parent = match on_parent {
+        true => GetParent(handle),
+        false => ptr::null_mut()
+    };
+
+    let monitor = match parent.is_null() {
+        true => {
+            let mut cursor = POINT { x: 0, y: 0 };
+            GetCursorPos(&mut cursor);
+            MonitorFromPoint(cursor, MONITOR_DEFAULTTONEAREST)
+        },
+        false => MonitorFromWindow(parent, MONITOR_DEFAULTTONEAREST)
+    };
+
+    let mut info: MONITORINFO = mem::zeroed();
+    info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    GetMonitorInfoW(monitor, &mut info);
+    let work = info.rcWork;
+
+    let target = match parent.is_null() {
+        true => work,
+        false => {
+            let mut r: RECT = mem::zeroed();
+            GetWindowRect(parent, &mut r);
+            r
+        }
+    };
+
+    let x = target.left + ((target.right - target.left) - width) / 2;
+    let y = target.top + ((target.bottom - target.top) - height) / 2;
+    let mut pt = POINT {
+        x: x.min(work.right - width).max(work.left),
+        y: y.min(work.bottom - height).max(work.top),
+    };
+
+    if get_style(handle) & WS_CHILD == WS_CHILD {
+        ScreenToClient(GetParent(handle), &mut pt);
+    }
+
+    SetWindowPos(handle, ptr::null_mut(), pt.x, pt.y, 0, 0, SWP_NOZORDER|SWP_NOSIZE|SWP_NOACTIVATE|SWP_NOOWNERZORDER);
+}
+
 pub unsafe fn set_window_size(handle: HWND, w: u32, h: u32, fix: bool) {
     use winapi::um::winuser::{SetWindowPos, AdjustWindowRectEx, GetWindowLongW};
     use winapi::um::winuser::{SWP_NOZORDER, SWP_NOMOVE, SWP_NOACTIVATE, SWP_NOCOPYBITS, GWL_STYLE, GWL_EXSTYLE, SWP_NOOWNERZORDER};
@@ -327,22 +882,16 @@ pub unsafe fn get_window_enabled(handle: HWND) -> bool {
 }
 
 pub unsafe fn set_window_enabled(handle: HWND, enabled: bool) {
-    use winapi::um::winuser::{GWL_STYLE, WS_DISABLED};
-    use winapi::um::winuser::{UpdateWindow, InvalidateRect};
+    use winapi::um::winuser::{UpdateWindow, InvalidateRect, EnableWindow};
 
-    let old_style = get_window_long(handle, GWL_STYLE) as usize;
-    if enabled {
-        set_window_long(handle, GWL_STYLE, old_style&(!WS_DISABLED as usize) );
-    } else {
-        set_window_long(handle, GWL_STYLE, old_style|(WS_DISABLED as usize));
-    }
+    // EnableWindow updates the `WS_DISABLED` style and sends `WM_ENABLE` if the state changed
+    EnableWindow(handle, enabled as BOOL);
 
     // Tell the control to redraw itself to show the new style.
     InvalidateRect(handle, ptr::null(), 1);
     UpdateWindow(handle);
 }
 
-#[cfg(feature = "tabs")]
 pub unsafe fn get_window_class_name(handle: HWND) -> String {
     use std::ffi::OsString;
     use std::os::windows::ffi::OsStringExt;